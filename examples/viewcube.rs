@@ -10,6 +10,7 @@ fn main() {
         .add_plugins(PanOrbitCameraPlugin)
         .add_plugins(MeshPickingPlugin)
         .add_plugins(BevyViewCubePlugin::default())
+        .add_plugins(BevyTridentPlugin)
         .add_systems(Startup, setup)
         .run();
 }
@@ -56,11 +57,14 @@ fn setup(
         GlobalTransform::default(),
     ));
 
-    // Trident
+    // Trident, the same size on screen whatever the zoom
+    let trident = BevyTridentAxis::default();
     commands.spawn((
-        Mesh3d(meshes.add(BevyTridentAxis::default())),
+        Mesh3d(meshes.add(trident)),
         MeshMaterial3d(materials.add(StandardMaterial::default())),
         Transform::default(),
         GlobalTransform::default(),
+        trident,
+        BevyTridentScreenSize::new(120.0),
     ));
 }
@@ -4,7 +4,6 @@ mod viewcube;
 use std::f32::consts::PI;
 const PI_2: f32 = PI / 2.0;
 const PI_4: f32 = PI / 4.0;
-const PI_4_3: f32 = PI / 4.0 * 3.0;
 
 #[derive(Component)]
 pub(crate) struct SmallView;
@@ -16,9 +15,66 @@ pub struct ViewcubeBinding;
 
 use bevy::{
     prelude::*,
-    render::{camera::ClearColorConfig, view::RenderLayers},
+    render::{
+        camera::{ClearColorConfig, Viewport},
+        view::RenderLayers,
+    },
 };
 
+/// Placement of the small view in the window, shared by the viewcube and the trident overlay.
+///
+/// Inserted with its default value by [`BevyViewCubePlugin`](viewcube::BevyViewCubePlugin) and
+/// [`BevyTridentOverlayPlugin`](trident::BevyTridentOverlayPlugin) if not already present, and
+/// can be changed at runtime.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct ViewcubeViewport {
+    /// Corner of the window the view sits in.
+    pub anchor: ViewcubeAnchor,
+    /// Width and height of the view as fractions of the window.
+    pub size: Vec2,
+}
+
+impl Default for ViewcubeViewport {
+    fn default() -> Self {
+        Self {
+            anchor: ViewcubeAnchor::BottomLeft,
+            size: Vec2::new(0.2, 0.3),
+        }
+    }
+}
+
+impl ViewcubeViewport {
+    /// Viewport of the small view in `window`.
+    pub fn viewport(&self, window: &Window) -> Viewport {
+        let window_size = window.physical_size();
+        let size = (window_size.as_vec2() * self.size)
+            .as_uvec2()
+            .clamp(UVec2::ONE, window_size.max(UVec2::ONE));
+        let free = window_size.saturating_sub(size);
+        let physical_position = match self.anchor {
+            ViewcubeAnchor::TopLeft => UVec2::ZERO,
+            ViewcubeAnchor::TopRight => UVec2::new(free.x, 0),
+            ViewcubeAnchor::BottomLeft => UVec2::new(0, free.y),
+            ViewcubeAnchor::BottomRight => free,
+        };
+        Viewport {
+            physical_position,
+            physical_size: size,
+            ..default()
+        }
+    }
+}
+
+/// Corner of the window holding the small view, see [`ViewcubeViewport::anchor`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ViewcubeAnchor {
+    TopLeft,
+    TopRight,
+    #[default]
+    BottomLeft,
+    BottomRight,
+}
+
 /// The function `create_small_view` creates a small 3D camera view with a directional light in Rust
 /// using the Bevy game engine.
 pub(crate) fn create_small_view(mut commands: bevy::ecs::system::Commands) {
@@ -41,7 +97,7 @@ pub(crate) fn create_small_view(mut commands: bevy::ecs::system::Commands) {
             RenderLayers::layer(13),
             SmallView,
             // bevy_picking::prelude::RaycastPickCamera::default(),
-            bevy_picking::mesh_picking::RayCastPickable::default(),
+            bevy_picking::mesh_picking::RayCastPickable,
         ))
         .with_children(|builder| {
             builder.spawn((
@@ -53,7 +109,17 @@ pub(crate) fn create_small_view(mut commands: bevy::ecs::system::Commands) {
 }
 
 pub mod prelude {
-    pub use crate::trident::{BevyTridentArrow, BevyTridentAxis, BevyTridentCone};
-    pub use crate::viewcube::BevyViewCubePlugin;
-    pub use crate::ViewcubeBinding;
+    pub use crate::trident::{
+        label_mesh, BevyTridentArrow, BevyTridentAxis, BevyTridentCone, BevyTridentDirection,
+        BevyTridentError, BevyTridentGizmos, BevyTridentLabels, BevyTridentLines,
+        BevyTridentOrigin, BevyTridentOverlayPlugin, BevyTridentPlugin, BevyTridentScreenSize,
+        BevyTridentStubs,
+    };
+    pub use crate::viewcube::{
+        BevyViewCubePlugin, CubePart, ViewcubeCornerView, ViewcubeEdgeView, ViewcubeHistory,
+        ViewcubeHistoryStep, ViewcubeLevel, ViewcubeMagnet, ViewcubeOutline, ViewcubeSettings,
+        ViewcubeSnap, ViewcubeStyle, ViewcubeTexture, ViewcubeTextures, ViewcubeUpAxis,
+        ViewcubeView, ViewcubeViewState, ViewcubeViews,
+    };
+    pub use crate::{ViewcubeAnchor, ViewcubeBinding, ViewcubeViewport};
 }
@@ -1,34 +1,274 @@
 pub mod arrow;
+mod error;
+mod gizmos;
+mod label;
+mod lines;
+mod overlay;
+mod screen_size;
 
 use bevy::{
+    app::{App, Plugin, PostUpdate, Update},
+    color::{Color, ColorToComponents},
+    ecs::{component::Component, schedule::IntoSystemConfigs, system::Resource},
     math::{
-        primitives::Sphere, Vec2, Vec3
+        primitives::{Cuboid, Sphere},
+        Quat, Vec2, Vec3,
     },
     render::{
-        mesh::{
-            Indices, Mesh, VertexAttributeValues
-        }, render_asset::RenderAssetUsages, render_resource::PrimitiveTopology
+        mesh::{Indices, Mesh, VertexAttributeValues},
+        render_asset::RenderAssetUsages,
+        render_resource::PrimitiveTopology,
     },
+    transform::TransformSystem,
 };
 
-pub use arrow::{
-    BevyTridentCone,
-    BevyTridentArrow
-};
+pub use arrow::{BevyTridentArrow, BevyTridentCone};
+pub use error::BevyTridentError;
+pub use gizmos::BevyTridentGizmos;
+pub use label::{label_mesh, BevyTridentLabels};
+pub use lines::BevyTridentLines;
+pub use overlay::BevyTridentOverlayPlugin;
+pub use screen_size::BevyTridentScreenSize;
+
+/// Positions, normals, uvs, indices and vertex colours of one part of the trident mesh.
+type TridentGeometry = (
+    Vec<[f32; 3]>,
+    Vec<[f32; 3]>,
+    Vec<[f32; 2]>,
+    Vec<u32>,
+    Vec<[f32; 4]>,
+);
+
+/// Systems for the trident extras, such as [`BevyTridentLabels`] and
+/// [`BevyTridentScreenSize`].
+#[derive(Default)]
+pub struct BevyTridentPlugin;
+
+impl Plugin for BevyTridentPlugin {
+    fn build(&self, app: &mut App) {
+        add_trident_systems(app);
+    }
+}
 
+/// Marks the trident systems as added, see [`add_trident_systems`].
+#[derive(Resource)]
+struct TridentSystems;
 
-#[derive(Debug, Clone, Copy, Default)]
+/// Adds the systems of [`BevyTridentPlugin`] once, so plugins needing them such as
+/// [`BevyTridentOverlayPlugin`] can be added before or after it.
+pub(crate) fn add_trident_systems(app: &mut App) {
+    if app.world().contains_resource::<TridentSystems>() {
+        return;
+    }
+    app.insert_resource(TridentSystems)
+        .add_systems(Update, label::update_labels)
+        .add_systems(
+            PostUpdate,
+            // Labels face the camera from the rescaled trident
+            (screen_size::scale_tridents, label::billboard_labels)
+                .chain()
+                .after(TransformSystem::TransformPropagate),
+        );
+}
+
+/// Trident mesh description, also a component so [`BevyTridentPlugin`] can place the extras of
+/// the trident drawn on the same entity.
+#[derive(Component, Debug, Clone, Copy)]
 pub struct BevyTridentAxis {
     pub axises: [BevyTridentArrow; 3],
+    /// Colours of the X, Y and Z arrows, and of their labels.
+    pub colors: [Color; 3],
+    /// Shape drawn at the origin.
+    pub origin: BevyTridentOrigin,
+    /// Stubs drawn towards -X, -Y and -Z, `None` to draw none.
+    pub negative: Option<BevyTridentStubs>,
+}
+
+/// One of the three axes of a trident, indexing [`BevyTridentAxis::axises`] and
+/// [`BevyTridentAxis::colors`] from 0 to 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BevyTridentDirection {
+    X,
+    Y,
+    Z,
+}
+
+impl BevyTridentDirection {
+    pub const ALL: [Self; 3] = [Self::X, Self::Y, Self::Z];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl TryFrom<usize> for BevyTridentDirection {
+    type Error = BevyTridentError;
+
+    fn try_from(dir: usize) -> Result<Self, Self::Error> {
+        Self::ALL
+            .get(dir)
+            .copied()
+            .ok_or(BevyTridentError::InvalidAxis(dir))
+    }
+}
+
+/// Shape at the origin of the trident, see [`BevyTridentAxis::origin`].
+///
+/// `size` is the distance from the origin to the surface along the axes: the radius of the
+/// sphere, half the edge of the cube, and the distance to the vertices of the octahedron. `None`
+/// follows the arrows, with the largest of their tail radii.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BevyTridentOrigin {
+    None,
+    Sphere { size: Option<f32>, color: Color },
+    Cube { size: Option<f32>, color: Color },
+    Octahedron { size: Option<f32>, color: Color },
+}
+
+impl BevyTridentOrigin {
+    /// Colour of the shape, `None` if there is none.
+    pub fn color(&self) -> Option<Color> {
+        match *self {
+            Self::None => None,
+            Self::Sphere { color, .. }
+            | Self::Cube { color, .. }
+            | Self::Octahedron { color, .. } => Some(color),
+        }
+    }
+
+    /// Size set for the shape, `None` if there is no shape or it follows the arrows.
+    fn size(&self) -> Option<f32> {
+        match *self {
+            Self::None => None,
+            Self::Sphere { size, .. } | Self::Cube { size, .. } | Self::Octahedron { size, .. } => {
+                size
+            }
+        }
+    }
+
+    /// Mesh of the shape alone of the given `size`, centered on the origin.
+    fn mesh(&self, size: f32) -> Option<Mesh> {
+        match *self {
+            Self::None => None,
+            Self::Sphere { .. } => Some(Mesh::from(Sphere { radius: size })),
+            Self::Cube { .. } => Some(Mesh::from(Cuboid::from_length(2.0 * size))),
+            Self::Octahedron { .. } => Some(octahedron_mesh(size)),
+        }
+    }
+}
+
+/// Octahedron with its vertices on the axes at `size` from the origin, flat shaded.
+fn octahedron_mesh(size: f32) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = vec![];
+    let mut normals: Vec<[f32; 3]> = vec![];
+    for signs in 0..8 {
+        let sign = |bit: usize| if signs & (1 << bit) == 0 { 1.0 } else { -1.0 };
+        let normal = Vec3::new(sign(0), sign(1), sign(2));
+        let mut corners = [Vec3::X * normal.x, Vec3::Y * normal.y, Vec3::Z * normal.z];
+        // Counter-clockwise seen from outside
+        if normal.x * normal.y * normal.z < 0.0 {
+            corners.swap(1, 2);
+        }
+        for corner in corners {
+            positions.push((corner * size).into());
+            normals.push(normal.normalize().into());
+        }
+    }
+    let uvs = vec![[0.0, 0.0]; positions.len()];
+    let indices = (0..positions.len() as u32).collect();
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_indices(Indices::U32(indices));
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh
+}
+
+/// Half-axes towards -X, -Y and -Z, see [`BevyTridentAxis::negative`].
+#[derive(Debug, Clone, Copy)]
+pub struct BevyTridentStubs {
+    /// Length of the stubs, as a fraction of the arrow on the positive side.
+    pub length: f32,
+    /// Colours of the -X, -Y and -Z stubs.
+    pub colors: [Color; 3],
+}
+
+impl Default for BevyTridentStubs {
+    /// A third of the arrows, in dimmed red, green and blue.
+    fn default() -> Self {
+        Self {
+            length: 1.0 / 3.0,
+            colors: [
+                Color::srgb(0.5, 0.2, 0.2),
+                Color::srgb(0.2, 0.5, 0.2),
+                Color::srgb(0.2, 0.2, 0.5),
+            ],
+        }
+    }
+}
+
+impl BevyTridentStubs {
+    /// Blunt arrow as thick as `arrow` and `length` times as long.
+    fn arrow(&self, arrow: BevyTridentArrow) -> BevyTridentArrow {
+        let length = (arrow.tail_length + arrow.cone.height) * self.length;
+        let tip = arrow.tail_radius.min(length);
+        BevyTridentArrow {
+            cone: BevyTridentCone {
+                radius: arrow.tail_radius,
+                height: tip,
+                subdivisions: arrow.cone.subdivisions,
+            },
+            tail_radius: arrow.tail_radius,
+            tail_length: length - tip,
+        }
+    }
+}
+
+impl Default for BevyTridentAxis {
+    fn default() -> Self {
+        Self {
+            axises: Default::default(),
+            colors: Self::RGB,
+            origin: BevyTridentOrigin::Sphere {
+                size: None,
+                color: Color::WHITE,
+            },
+            negative: None,
+        }
+    }
 }
 
 impl BevyTridentAxis {
+    /// Red X, green Y and blue Z.
+    pub const RGB: [Color; 3] = [
+        Color::srgb(1.0, 0.0, 0.0),
+        Color::srgb(0.0, 1.0, 0.0),
+        Color::srgb(0.0, 0.0, 1.0),
+    ];
+    /// Orange X, sky blue Y and yellow Z from the Okabe-Ito palette, told apart with the common
+    /// colour vision deficiencies.
+    pub const COLORBLIND: [Color; 3] = [
+        Color::srgb(0.90, 0.62, 0.0),
+        Color::srgb(0.34, 0.71, 0.91),
+        Color::srgb(0.94, 0.89, 0.26),
+    ];
+
     pub const TRIDENT_10: BevyTridentAxis = BevyTridentAxis {
         axises: [
             BevyTridentArrow::TRIDENT_ARROW_10,
             BevyTridentArrow::TRIDENT_ARROW_10,
             BevyTridentArrow::TRIDENT_ARROW_10,
-        ]
+        ],
+        colors: Self::RGB,
+        origin: BevyTridentOrigin::Sphere {
+            size: None,
+            color: Color::WHITE,
+        },
+        negative: None,
     };
     pub const TRIDENT_100: BevyTridentAxis = BevyTridentAxis {
         axises: [
@@ -36,40 +276,213 @@ impl BevyTridentAxis {
             BevyTridentArrow::TRIDENT_ARROW_100,
             BevyTridentArrow::TRIDENT_ARROW_100,
         ],
+        colors: Self::RGB,
+        origin: BevyTridentOrigin::Sphere {
+            size: None,
+            color: Color::WHITE,
+        },
+        negative: None,
     };
 
+    /// Replaces the colours of the X, Y and Z arrows.
+    pub fn with_colors(mut self, colors: [Color; 3]) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Draws stubs towards -X, -Y and -Z.
+    pub fn with_negative(mut self, stubs: BevyTridentStubs) -> Self {
+        self.negative = Some(stubs);
+        self
+    }
+
+    /// Replaces the shape at the origin.
+    pub fn with_origin(mut self, origin: BevyTridentOrigin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Size of the shape at the origin, its own or the largest tail radius of the arrows.
+    pub fn origin_size(&self) -> f32 {
+        self.origin.size().unwrap_or_else(|| {
+            self.axises
+                .iter()
+                .map(|arrow| arrow.tail_radius)
+                .fold(0.0, f32::max)
+        })
+    }
+
+    /// Replaces the colour of the shape at the origin, if any.
+    pub fn with_origin_color(mut self, color: Color) -> Self {
+        match &mut self.origin {
+            BevyTridentOrigin::None => {}
+            BevyTridentOrigin::Sphere { color: origin, .. }
+            | BevyTridentOrigin::Cube { color: origin, .. }
+            | BevyTridentOrigin::Octahedron { color: origin, .. } => *origin = color,
+        }
+        self
+    }
 }
 
 impl From<BevyTridentAxis> for Mesh {
+    /// Builds the mesh without checking the parameters, see [`BevyTridentAxis::try_into_mesh`].
     fn from(trident: BevyTridentAxis) -> Self {
-        let (x_points, x_normals, x_uvs, x_indices, x_colors) = trident.gen_axis(0);
-        let (y_points, y_normals, y_uvs, y_indices, y_colors) = trident.gen_axis(1);
-        let (z_points, z_normals, z_uvs, z_indices, z_colors) = trident.gen_axis(2);
-        let (o_points, o_normals, o_uvs, o_indices, o_colors) = trident.gen_origin(
-            x_points.len() + y_points.len() + z_points.len()
-        );
+        trident.build().unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl BevyTridentAxis {
+    /// Mesh of the trident, or why its parameters would give a broken one.
+    pub fn try_into_mesh(&self) -> Result<Mesh, BevyTridentError> {
+        self.validate()?;
+        self.build()
+    }
+
+    /// Checks the radii, lengths and subdivisions of the arrows, the stubs and the origin.
+    pub fn validate(&self) -> Result<(), BevyTridentError> {
+        for (axis, arrow) in self.axises.iter().enumerate() {
+            let positive = [
+                ("cone radius", arrow.cone.radius),
+                ("cone height", arrow.cone.height),
+                ("tail radius", arrow.tail_radius),
+            ];
+            for (field, value) in positive {
+                if !(value.is_finite() && value > 0.0) {
+                    return Err(BevyTridentError::InvalidArrow { axis, field, value });
+                }
+            }
+            // A tail of length zero leaves the cone alone
+            if !(arrow.tail_length.is_finite() && arrow.tail_length >= 0.0) {
+                return Err(BevyTridentError::InvalidArrow {
+                    axis,
+                    field: "tail length",
+                    value: arrow.tail_length,
+                });
+            }
+            if arrow.cone.subdivisions < 3 {
+                return Err(BevyTridentError::TooFewSubdivisions {
+                    axis,
+                    subdivisions: arrow.cone.subdivisions,
+                });
+            }
+        }
+        if let Some(stubs) = self.negative {
+            if !(stubs.length.is_finite() && stubs.length > 0.0) {
+                return Err(BevyTridentError::InvalidStubLength(stubs.length));
+            }
+        }
+        // Following the arrows, the size is a tail radius checked above
+        if let Some(size) = self.origin.size() {
+            if !(size.is_finite() && size > 0.0) {
+                return Err(BevyTridentError::InvalidOriginSize(size));
+            }
+        }
+        Ok(())
+    }
+
+    /// Merged mesh of the arrows, stubs and origin, failing only if the origin shape has no mesh.
+    fn build(&self) -> Result<Mesh, BevyTridentError> {
+        let mut parts = vec![];
+        let mut shift = 0;
+        for dir in BevyTridentDirection::ALL {
+            let axis = self.gen_axis(
+                dir,
+                self.axises[dir.index()],
+                self.colors[dir.index()],
+                false,
+                shift,
+            );
+            shift += axis.0.len();
+            parts.push(axis);
+        }
+        if let Some(stubs) = self.negative {
+            for dir in BevyTridentDirection::ALL {
+                let stub = self.gen_axis(
+                    dir,
+                    stubs.arrow(self.axises[dir.index()]),
+                    stubs.colors[dir.index()],
+                    true,
+                    shift,
+                );
+                shift += stub.0.len();
+                parts.push(stub);
+            }
+        }
+        parts.push(self.gen_origin(shift)?);
+        Ok(geometry_mesh(parts))
+    }
+}
+
+/// Mesh merging the parts of a trident, their indices already shifted to follow each other.
+fn geometry_mesh(parts: Vec<TridentGeometry>) -> Mesh {
+    let (mut positions, mut normals, mut uvs, mut indices, mut colors) =
+        (vec![], vec![], vec![], vec![], vec![]);
+    for (p, n, u, i, c) in parts {
+        positions.extend(p);
+        normals.extend(n);
+        uvs.extend(u);
+        indices.extend(i);
+        colors.extend(c);
+    }
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_indices(Indices::U32(indices));
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    mesh
+}
+
+impl BevyTridentAxis {
+    /// Mesh of the arrow along `dir`, with its negative stub if any, e.g. to pick the axes
+    /// separately.
+    pub fn axis_mesh(&self, dir: BevyTridentDirection) -> Mesh {
+        let (arrow, color) = (self.axises[dir.index()], self.colors[dir.index()]);
+        let mut parts = vec![self.gen_axis(dir, arrow, color, false, 0)];
+        if let Some(stubs) = self.negative {
+            let shift = parts[0].0.len();
+            parts.push(self.gen_axis(
+                dir,
+                stubs.arrow(arrow),
+                stubs.colors[dir.index()],
+                true,
+                shift,
+            ));
+        }
+        geometry_mesh(parts)
+    }
 
-        let positions = [x_points, y_points, z_points, o_points].concat();
-        let normals = [x_normals, y_normals, z_normals, o_normals].concat();
-        let uvs = [x_uvs, y_uvs, z_uvs, o_uvs].concat();
-        let indices = [x_indices, y_indices, z_indices, o_indices].concat();
-        let colors = [x_colors, y_colors, z_colors, o_colors].concat();
+    /// [`BevyTridentAxis::axis_mesh`] after checking the parameters.
+    pub fn try_axis_mesh(&self, dir: BevyTridentDirection) -> Result<Mesh, BevyTridentError> {
+        self.validate()?;
+        Ok(self.axis_mesh(dir))
+    }
 
-        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
-        mesh.insert_indices(Indices::U32(indices));
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
-        mesh
+    /// Mesh of the origin shape alone, see [`BevyTridentAxis::axis_mesh`].
+    pub fn origin_mesh(&self) -> Mesh {
+        geometry_mesh(vec![self
+            .gen_origin(0)
+            .unwrap_or_else(|error| panic!("{error}"))])
     }
 }
 
 impl BevyTridentAxis {
-    fn gen_axis(&self, dir: usize)
-    -> (Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<[f32; 2]>, Vec<u32>, Vec<[f32; 4]>) {
-        let d = self.axises[dir].cone.subdivisions;
-        let n_vertices = 3 * (self.axises[dir].cone.subdivisions + 1) + 2;
+    /// Arrow along `dir`, towards the negative side if `negative`, with its indices shifted by
+    /// `shift` to follow the vertices generated before it.
+    fn gen_axis(
+        &self,
+        dir: BevyTridentDirection,
+        arrow: BevyTridentArrow,
+        color: Color,
+        negative: bool,
+        shift: usize,
+    ) -> TridentGeometry {
+        let d = arrow.cone.subdivisions;
+        let n_vertices = 3 * (arrow.cone.subdivisions + 1) + 2;
         let mut positions: Vec<[f32; 3]> = Vec::with_capacity(n_vertices);
         let mut normals: Vec<[f32; 3]> = Vec::with_capacity(n_vertices);
         let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(n_vertices);
@@ -80,17 +493,23 @@ impl BevyTridentAxis {
         uvs.resize_with(n_vertices, Default::default);
 
         let uv_stride = match dir {
-            0 => Vec2::ZERO,
-            1 => Vec2::new(0.5, 0.0),
-            2 => Vec2::new(0.0, 0.5),
-            _ => panic!("Invalid axis"),
+            BevyTridentDirection::X => Vec2::ZERO,
+            BevyTridentDirection::Y => Vec2::new(0.5, 0.0),
+            BevyTridentDirection::Z => Vec2::new(0.0, 0.5),
         };
+        // Around the axis, from the next one towards the one after
+        let circle = |phi: f32| match dir {
+            BevyTridentDirection::X => Vec3::new(0.0, phi.cos(), phi.sin()),
+            BevyTridentDirection::Y => Vec3::new(phi.sin(), 0.0, phi.cos()),
+            BevyTridentDirection::Z => Vec3::new(phi.cos(), phi.sin(), 0.0),
+        };
+        let dir = dir.index();
 
-        let cr = self.axises[dir].cone.radius;
-        let tr = self.axises[dir].tail_radius;
+        let cr = arrow.cone.radius;
+        let tr = arrow.tail_radius;
         // top
         let mut top = Vec3::ZERO;
-        top[dir] = self.axises[dir].tail_length + self.axises[dir].cone.height;
+        top[dir] = arrow.tail_length + arrow.cone.height;
         positions[0] = top.into();
         normals[0] = top.normalize().into();
         normals[1] = (-top.normalize()).into();
@@ -98,25 +517,20 @@ impl BevyTridentAxis {
         uvs[1] = (Vec2::new(0.5, 0.25) + uv_stride).into();
 
         // Cone circular
-        let stride = 2.0 * std::f32::consts::PI / self.axises[dir].cone.subdivisions as f32;
-        (0..=self.axises[dir].cone.subdivisions).for_each(|i| {
+        let stride = 2.0 * std::f32::consts::PI / arrow.cone.subdivisions as f32;
+        (0..=arrow.cone.subdivisions).for_each(|i| {
             let phi = i as f32 * stride;
-            let vertice = match dir {
-                0 => Vec3::new(0.0, phi.cos(), phi.sin()),
-                1 => Vec3::new(phi.sin(), 0.0, phi.cos()),
-                2 => Vec3::new(phi.cos(), phi.sin(), 0.0),
-                _ => panic!("Invalid axis"),
-            };
+            let vertice = circle(phi);
 
             let mut cone_vertice = vertice * cr;
             let mut converge_vertice = vertice * tr;
             let cylinder_vertice = vertice * tr;
-            cone_vertice[dir] = self.axises[dir].tail_length;
-            converge_vertice[dir] = self.axises[dir].tail_length;
+            cone_vertice[dir] = arrow.tail_length;
+            converge_vertice[dir] = arrow.tail_length;
 
             let i1 = i + 2;
-            let i2 = i + (self.axises[dir].cone.subdivisions + 1) + 2;
-            let i3 = i + 2 * (self.axises[dir].cone.subdivisions + 1) + 2;
+            let i2 = i + (arrow.cone.subdivisions + 1) + 2;
+            let i3 = i + 2 * (arrow.cone.subdivisions + 1) + 2;
 
             // position
             positions[i1] = cone_vertice.into();
@@ -125,10 +539,16 @@ impl BevyTridentAxis {
 
             // normal
             let unit_to_top = top.normalize();
-            let unit_converge_to_cone = (cone_vertice - converge_vertice).normalize();
-            let unit_cylinder = (cylinder_vertice - converge_vertice).normalize();
+            // Cones as wide as the tail and tails of length zero have no step to take the
+            // direction of, fall back to the ones it has otherwise
+            let unit_converge_to_cone = (cone_vertice - converge_vertice)
+                .try_normalize()
+                .unwrap_or(vertice);
+            let unit_cylinder = (cylinder_vertice - converge_vertice)
+                .try_normalize()
+                .unwrap_or(-unit_to_top);
             // normal cone
-            let tmp = unit_converge_to_cone.cross(unit_to_top).normalize();
+            let tmp = vertice.cross(unit_to_top);
             let cone_normal = ((top - cone_vertice).normalize()).cross(tmp).normalize();
             let converge_normal = (unit_converge_to_cone + unit_cylinder).normalize();
             let cylinder_normal = (unit_cylinder + cylinder_vertice.normalize()).normalize();
@@ -150,7 +570,7 @@ impl BevyTridentAxis {
             let top_left = point + 1;
             let top_right = point;
 
-            let bottom_left =  2 * (d + 1) + point + 1;
+            let bottom_left = 2 * (d + 1) + point + 1;
             let bottom_right = 2 * (d + 1) + point;
 
             indices.push(top as u32);
@@ -185,86 +605,258 @@ impl BevyTridentAxis {
             indices.push(i_cylinder as u32);
             indices.push(i_cylinder_next as u32);
         }
-        if dir != 0 {
-            let axis_stride = dir * n_vertices;
-            for index in indices.iter_mut() {
-                *index += axis_stride as u32;
+        if negative {
+            // Half a turn around the next axis keeps the winding, unlike a mirror
+            let mut around = Vec3::ZERO;
+            around[(dir + 1) % 3] = 1.0;
+            let turn = Quat::from_axis_angle(around, std::f32::consts::PI);
+            for vertex in positions.iter_mut().chain(normals.iter_mut()) {
+                *vertex = (turn * Vec3::from(*vertex)).into();
             }
         }
-        let mut colors: Vec<[f32; 4]> = vec![];
-        colors.resize_with(
-            positions.len(),
-            || {
-                match dir {
-                    0 => [1.0, 0.0, 0.0, 1.0],
-                    1 => [0.0, 1.0, 0.0, 1.0],
-                    2 => [0.0, 0.0, 1.0, 1.0],
-                    _ => panic!("Invalid axis"),
-                }
-            }
-        );
+        for index in indices.iter_mut() {
+            *index += shift as u32;
+        }
+        let colors = vec![color.to_linear().to_f32_array(); positions.len()];
 
-        (
-            positions,
-            normals,
-            uvs,
-            indices,
-            colors
-        )
-    }
-
-    fn gen_origin(&self, shift: usize)
-    -> (Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<[f32; 2]>, Vec<u32>, Vec<[f32; 4]>)  {
-        let mut radius = self.axises[0].tail_radius;
-        self.axises.iter().skip(1).for_each(|axis| {
-            if axis.tail_radius > radius {
-                radius = axis.tail_radius;
-            }
-        });
-        // Choose largest radius for sphere
-        let sphere = Sphere{radius};
-        let mesh = Mesh::from(sphere);
+        (positions, normals, uvs, indices, colors)
+    }
+
+    /// Shape at the origin, with its indices shifted by `shift`, empty for
+    /// [`BevyTridentOrigin::None`].
+    fn gen_origin(&self, shift: usize) -> Result<TridentGeometry, BevyTridentError> {
+        let (Some(mesh), Some(color)) = (self.origin.mesh(self.origin_size()), self.origin.color())
+        else {
+            return Ok((vec![], vec![], vec![], vec![], vec![]));
+        };
 
         let positions = if let Some(VertexAttributeValues::Float32x3(vert_positions)) =
             &mesh.attribute(Mesh::ATTRIBUTE_POSITION)
         {
             vert_positions.clone()
         } else {
-            panic!("no positions")
+            return Err(BevyTridentError::MissingAttribute("positions"));
         };
         let normals = if let Some(VertexAttributeValues::Float32x3(normals)) =
             &mesh.attribute(Mesh::ATTRIBUTE_NORMAL)
         {
             normals.clone()
         } else {
-            panic!("no normals")
+            return Err(BevyTridentError::MissingAttribute("normals"));
         };
         let uvs = if let Some(VertexAttributeValues::Float32x2(uvs)) =
             &mesh.attribute(Mesh::ATTRIBUTE_UV_0)
         {
             uvs.clone()
         } else {
-            panic!("no uvs")
+            return Err(BevyTridentError::MissingAttribute("uvs"));
         };
 
         let shift = shift as u32;
-        let indices: Vec<u32> = if let Some(Indices::U32(indices)) =
-            &mesh.indices()
-        {
+        let indices: Vec<u32> = if let Some(Indices::U32(indices)) = &mesh.indices() {
             indices.iter().map(|i| *i + shift).collect()
         } else {
-            panic!("no indices")
+            return Err(BevyTridentError::MissingAttribute("indices"));
         };
-        let mut colors: Vec<[f32; 4]> = vec![];
-        colors.resize_with(positions.len(), || [1.0, 1.0, 1.0, 1.0]);
+        let colors = vec![color.to_linear().to_f32_array(); positions.len()];
 
-        (
-            positions,
-            normals,
-            uvs,
-            indices,
-            colors
-        )
+        Ok((positions, normals, uvs, indices, colors))
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use bevy::render::mesh::{Indices, Mesh};
+
+    use super::*;
+
+    const INVALID: [f32; 4] = [0.0, -1.0, f32::NAN, f32::INFINITY];
+
+    /// Writes a value into one field of an arrow.
+    type ArrowSetter = fn(&mut BevyTridentArrow, f32);
+
+    #[test]
+    fn default_trident_is_valid() {
+        assert_eq!(BevyTridentAxis::default().validate(), Ok(()));
+        assert_eq!(BevyTridentAxis::TRIDENT_10.validate(), Ok(()));
+        assert_eq!(BevyTridentAxis::TRIDENT_100.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_invalid_arrows() {
+        for value in INVALID {
+            let setters: [(&str, ArrowSetter); 3] = [
+                ("cone radius", |arrow, value| arrow.cone.radius = value),
+                ("cone height", |arrow, value| arrow.cone.height = value),
+                ("tail radius", |arrow, value| arrow.tail_radius = value),
+            ];
+            for (field, set) in setters {
+                let mut trident = BevyTridentAxis::default();
+                set(&mut trident.axises[1], value);
+                assert!(
+                    matches!(
+                        trident.validate(),
+                        Err(BevyTridentError::InvalidArrow { axis: 1, field: f, .. }) if f == field
+                    ),
+                    "{field} {value}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn validate_rejects_invalid_tail_length() {
+        for value in [-1.0, f32::NAN, f32::INFINITY] {
+            let mut trident = BevyTridentAxis::default();
+            trident.axises[2].tail_length = value;
+            assert!(matches!(
+                trident.validate(),
+                Err(BevyTridentError::InvalidArrow {
+                    axis: 2,
+                    field: "tail length",
+                    ..
+                })
+            ));
+        }
+        let mut trident = BevyTridentAxis::default();
+        trident.axises[2].tail_length = 0.0;
+        assert_eq!(trident.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_too_few_subdivisions() {
+        let mut trident = BevyTridentAxis::default();
+        trident.axises[0].cone.subdivisions = 2;
+        assert_eq!(
+            trident.validate(),
+            Err(BevyTridentError::TooFewSubdivisions {
+                axis: 0,
+                subdivisions: 2
+            })
+        );
+    }
+
+    #[test]
+    fn validate_rejects_invalid_stub_length() {
+        for value in INVALID {
+            let trident = BevyTridentAxis::default().with_negative(BevyTridentStubs {
+                length: value,
+                ..Default::default()
+            });
+            assert!(matches!(
+                trident.validate(),
+                Err(BevyTridentError::InvalidStubLength(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn validate_rejects_invalid_origin_size() {
+        for value in INVALID {
+            for origin in [
+                BevyTridentOrigin::Sphere {
+                    size: Some(value),
+                    color: Color::WHITE,
+                },
+                BevyTridentOrigin::Cube {
+                    size: Some(value),
+                    color: Color::WHITE,
+                },
+                BevyTridentOrigin::Octahedron {
+                    size: Some(value),
+                    color: Color::WHITE,
+                },
+            ] {
+                let trident = BevyTridentAxis::default().with_origin(origin);
+                assert!(matches!(
+                    trident.validate(),
+                    Err(BevyTridentError::InvalidOriginSize(_))
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn direction_rejects_invalid_axis() {
+        assert_eq!(
+            BevyTridentDirection::try_from(3),
+            Err(BevyTridentError::InvalidAxis(3))
+        );
+        for (index, dir) in BevyTridentDirection::ALL.into_iter().enumerate() {
+            assert_eq!(BevyTridentDirection::try_from(index), Ok(dir));
+            assert_eq!(dir.index(), index);
+        }
+        let trident = BevyTridentAxis::default();
+        assert!(trident.try_axis_mesh(BevyTridentDirection::Z).is_ok());
+    }
+
+    #[test]
+    fn error_display_handles_any_axis() {
+        let error = BevyTridentError::InvalidArrow {
+            axis: 3,
+            field: "tail radius",
+            value: 0.0,
+        };
+        assert_eq!(error.to_string(), "invalid tail radius 0 for the #3 arrow");
+    }
+
+    #[test]
+    fn mesh_indices_stay_within_vertices() {
+        let origins = [
+            BevyTridentOrigin::None,
+            BevyTridentOrigin::Sphere {
+                size: Some(0.1),
+                color: Color::WHITE,
+            },
+            BevyTridentOrigin::Cube {
+                size: Some(0.1),
+                color: Color::WHITE,
+            },
+            BevyTridentOrigin::Octahedron {
+                size: Some(0.1),
+                color: Color::WHITE,
+            },
+        ];
+        for origin in origins {
+            for negative in [None, Some(BevyTridentStubs::default())] {
+                let trident = BevyTridentAxis {
+                    origin,
+                    negative,
+                    ..Default::default()
+                };
+                let mesh: Mesh = trident.try_into_mesh().unwrap();
+                let vertices = mesh.count_vertices() as u32;
+                let Some(Indices::U32(indices)) = mesh.indices() else {
+                    panic!("no u32 indices for {origin:?}");
+                };
+                assert_eq!(indices.len() % 3, 0);
+                assert!(
+                    indices.iter().all(|&index| index < vertices),
+                    "index out of {vertices} vertices for {origin:?}, stubs {}",
+                    negative.is_some()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn default_origin_follows_the_largest_tail_radius() {
+        let mut trident = BevyTridentAxis::default();
+        assert_eq!(
+            trident.origin_size(),
+            BevyTridentArrow::default().tail_radius
+        );
+        trident.axises[1].tail_radius = 0.07;
+        assert_eq!(trident.origin_size(), 0.07);
+        assert_eq!(
+            BevyTridentAxis::TRIDENT_100.origin_size(),
+            BevyTridentArrow::TRIDENT_ARROW_100.tail_radius
+        );
+
+        let trident = trident.with_origin(BevyTridentOrigin::Cube {
+            size: Some(0.01),
+            color: Color::WHITE,
+        });
+        assert_eq!(trident.origin_size(), 0.01);
+    }
+}
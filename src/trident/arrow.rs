@@ -54,17 +54,24 @@ impl BevyTridentArrow {
     }
 
     pub fn new_with_detail(
-        length: f32, radius: f32, subdivisions: usize,
-        cone_length_ratio: f32, cone_radius_ratio: f32
+        length: f32,
+        radius: f32,
+        subdivisions: usize,
+        cone_length_ratio: f32,
+        cone_radius_ratio: f32,
     ) -> Self {
         let subdivisions = if subdivisions < 3 { 3 } else { subdivisions };
         let cone_length = length * cone_length_ratio;
         let cone_radius = radius * cone_radius_ratio;
 
         Self {
-            cone: BevyTridentCone { radius: cone_radius, height: cone_length, subdivisions },
+            cone: BevyTridentCone {
+                radius: cone_radius,
+                height: cone_length,
+                subdivisions,
+            },
             tail_radius: radius,
             tail_length: length - cone_length,
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,55 @@
+use std::{error::Error, fmt};
+
+/// Why [`BevyTridentAxis::try_into_mesh`](super::BevyTridentAxis::try_into_mesh) could not build
+/// a trident mesh.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BevyTridentError {
+    /// An axis other than 0 to 2 for X to Z.
+    InvalidAxis(usize),
+    /// A radius or length of the arrow along `axis` is negative, not finite, or zero where the
+    /// arrow would collapse.
+    InvalidArrow {
+        axis: usize,
+        field: &'static str,
+        value: f32,
+    },
+    /// The cone of the arrow along `axis` has fewer than 3 sides.
+    TooFewSubdivisions { axis: usize, subdivisions: usize },
+    /// [`BevyTridentStubs::length`](super::BevyTridentStubs::length) is not positive and finite.
+    InvalidStubLength(f32),
+    /// The size of the [`BevyTridentOrigin`](super::BevyTridentOrigin) is not positive and
+    /// finite.
+    InvalidOriginSize(f32),
+    /// The mesh of the origin shape lacks this vertex attribute or its indices.
+    MissingAttribute(&'static str),
+}
+
+impl fmt::Display for BevyTridentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAxis(axis) => write!(f, "invalid axis {axis}, expected 0 to 2"),
+            Self::InvalidArrow { axis, field, value } => write!(
+                f,
+                "invalid {field} {value} for the {} arrow",
+                axis_name(*axis)
+            ),
+            Self::TooFewSubdivisions { axis, subdivisions } => write!(
+                f,
+                "{subdivisions} subdivisions for the {} cone, expected at least 3",
+                axis_name(*axis)
+            ),
+            Self::InvalidStubLength(length) => write!(f, "invalid stub length {length}"),
+            Self::InvalidOriginSize(size) => write!(f, "invalid origin size {size}"),
+            Self::MissingAttribute(attribute) => write!(f, "origin mesh has no {attribute}"),
+        }
+    }
+}
+
+impl Error for BevyTridentError {}
+
+/// `X`, `Y` or `Z`, or the number of an axis out of range.
+fn axis_name(axis: usize) -> String {
+    ["X", "Y", "Z"]
+        .get(axis)
+        .map_or_else(|| format!("#{axis}"), |name| name.to_string())
+}
@@ -0,0 +1,87 @@
+use bevy::{
+    gizmos::{config::GizmoConfigGroup, gizmos::Gizmos, primitives::dim3::GizmoPrimitive3d},
+    math::{primitives::Cone, Isometry3d, Quat, Vec3},
+    transform::components::{GlobalTransform, Transform},
+};
+
+use super::{BevyTridentAxis, BevyTridentOrigin};
+
+/// Draws a [`BevyTridentAxis`] with gizmos for one frame, e.g. at the pivot of a selection.
+pub trait BevyTridentGizmos {
+    /// Trident of the shape and colours of `trident`, placed with `transform` as its mesh would
+    /// be: tails as lines, cones as wireframes, the negative stubs as lines and the origin as the
+    /// wireframe of its shape.
+    fn trident(&mut self, transform: impl Into<GlobalTransform>, trident: &BevyTridentAxis);
+}
+
+impl<Config, Clear> BevyTridentGizmos for Gizmos<'_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn trident(&mut self, transform: impl Into<GlobalTransform>, trident: &BevyTridentAxis) {
+        let transform: GlobalTransform = transform.into();
+        let (scale, rotation, _) = transform.to_scale_rotation_translation();
+
+        for dir in 0..3 {
+            let arrow = trident.axises[dir];
+            let color = trident.colors[dir];
+            let mut axis = Vec3::ZERO;
+            axis[dir] = 1.0;
+
+            let base = transform.transform_point(axis * arrow.tail_length);
+            self.line(transform.translation(), base, color);
+
+            let height = arrow.cone.height * scale[dir];
+            let radius = arrow.cone.radius * scale[(dir + 1) % 3].max(scale[(dir + 2) % 3]);
+            // Gizmo cones point up +Y from their middle
+            let direction = rotation * axis;
+            let isometry = Isometry3d::new(
+                base + direction * height / 2.0,
+                Quat::from_rotation_arc(Vec3::Y, direction),
+            );
+            self.primitive_3d(&Cone { radius, height }, isometry, color)
+                .base_resolution(arrow.cone.subdivisions as u32)
+                .height_resolution(arrow.cone.subdivisions as u32);
+
+            if let Some(stubs) = trident.negative {
+                let length = (arrow.tail_length + arrow.cone.height) * stubs.length;
+                self.line(
+                    transform.translation(),
+                    transform.transform_point(-axis * length),
+                    stubs.colors[dir],
+                );
+            }
+        }
+
+        let size = trident.origin_size();
+        match trident.origin {
+            BevyTridentOrigin::None => {}
+            BevyTridentOrigin::Sphere { color, .. } => {
+                self.sphere(
+                    Isometry3d::new(transform.translation(), rotation),
+                    size * scale.max_element(),
+                    color,
+                );
+            }
+            BevyTridentOrigin::Cube { color, .. } => {
+                self.cuboid(
+                    transform.mul_transform(Transform::from_scale(Vec3::splat(2.0 * size))),
+                    color,
+                );
+            }
+            BevyTridentOrigin::Octahedron { color, .. } => {
+                let vertex = |dir: usize, sign: f32| {
+                    let mut axis = Vec3::ZERO;
+                    axis[dir] = sign * size;
+                    transform.transform_point(axis)
+                };
+                for dir in 0..3 {
+                    for (sign, next_sign) in [(1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)] {
+                        self.line(vertex(dir, sign), vertex((dir + 1) % 3, next_sign), color);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use bevy::{
+    asset::Assets,
+    color::{Color, ColorToComponents},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::{Changed, Or, With, Without},
+        system::{Commands, Query, ResMut},
+    },
+    hierarchy::{BuildChildren, ChildBuild, DespawnRecursiveExt, Parent},
+    math::{Vec2, Vec3},
+    pbr::{MeshMaterial3d, StandardMaterial},
+    prelude::{default, Mesh3d},
+    render::{
+        camera::Camera,
+        mesh::{Indices, Mesh},
+        render_asset::RenderAssetUsages,
+        render_resource::PrimitiveTopology,
+        view::RenderLayers,
+    },
+    transform::components::{GlobalTransform, Transform},
+};
+
+use super::BevyTridentAxis;
+
+/// Distance between two characters relative to their height.
+const ADVANCE: f32 = 0.75;
+/// Thickness of a segment relative to the character height.
+const STROKE: f32 = 0.08;
+
+/// Ends of the 16 segments of a character cell, in units of its height, centered on the origin.
+///
+/// Named after the usual 16-segment display layout: `a` top, `b` `c` right, `d` bottom, `e` `f`
+/// left, `g` middle, `h` `j` `k` `m` diagonals and `i` `l` center verticals, halves numbered
+/// from the left.
+const SEGMENTS: [(&str, [f32; 2], [f32; 2]); 16] = [
+    ("a1", [-0.25, 0.5], [0.0, 0.5]),
+    ("a2", [0.0, 0.5], [0.25, 0.5]),
+    ("b", [0.25, 0.5], [0.25, 0.0]),
+    ("c", [0.25, 0.0], [0.25, -0.5]),
+    ("d1", [0.25, -0.5], [0.0, -0.5]),
+    ("d2", [0.0, -0.5], [-0.25, -0.5]),
+    ("e", [-0.25, -0.5], [-0.25, 0.0]),
+    ("f", [-0.25, 0.0], [-0.25, 0.5]),
+    ("g1", [-0.25, 0.0], [0.0, 0.0]),
+    ("g2", [0.0, 0.0], [0.25, 0.0]),
+    ("h", [-0.25, 0.5], [0.0, 0.0]),
+    ("i", [0.0, 0.5], [0.0, 0.0]),
+    ("j", [0.25, 0.5], [0.0, 0.0]),
+    ("k", [0.0, 0.0], [0.25, -0.5]),
+    ("l", [0.0, 0.0], [0.0, -0.5]),
+    ("m", [0.0, 0.0], [-0.25, -0.5]),
+];
+
+/// Segments lit for a character, `None` for the ones drawn blank.
+fn glyph(c: char) -> Option<&'static str> {
+    let segments = match c.to_ascii_uppercase() {
+        'A' => "a1 a2 b c e f g1 g2",
+        'B' => "a1 a2 b c d1 d2 g2 i l",
+        'C' => "a1 a2 d1 d2 e f",
+        'D' => "a1 a2 b c d1 d2 i l",
+        'E' => "a1 a2 d1 d2 e f g1",
+        'F' => "a1 a2 e f g1",
+        'G' => "a1 a2 c d1 d2 e f g2",
+        'H' => "b c e f g1 g2",
+        'I' => "a1 a2 d1 d2 i l",
+        'J' => "b c d1 d2 e",
+        'K' => "e f g1 j k",
+        'L' => "d1 d2 e f",
+        'M' => "b c e f h j",
+        'N' => "b c e f h k",
+        'O' | '0' => "a1 a2 b c d1 d2 e f",
+        'P' => "a1 a2 b e f g1 g2",
+        'Q' => "a1 a2 b c d1 d2 e f k",
+        'R' => "a1 a2 b e f g1 g2 k",
+        'S' | '5' => "a1 a2 c d1 d2 f g1 g2",
+        'T' => "a1 a2 i l",
+        'U' => "b c d1 d2 e f",
+        'V' => "e f j m",
+        'W' => "b c e f k m",
+        'X' => "h j k m",
+        'Y' => "h j l",
+        'Z' => "a1 a2 d1 d2 j m",
+        '1' => "b c j",
+        '2' => "a1 a2 b d1 d2 e g1 g2",
+        '3' => "a1 a2 b c d1 d2 g2",
+        '4' => "b c f g1 g2",
+        '6' => "a1 a2 c d1 d2 e f g1 g2",
+        '7' => "a1 a2 b c",
+        '8' => "a1 a2 b c d1 d2 e f g1 g2",
+        '9' => "a1 a2 b c d1 d2 f g1 g2",
+        '-' => "g1 g2",
+        '+' => "g1 g2 i l",
+        _ => return None,
+    };
+    Some(segments)
+}
+
+/// Flat mesh of `text` drawn with 16-segment characters of height `size`, centered on the
+/// origin and facing +Z.
+pub fn label_mesh(text: &str, size: f32, color: Color) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = vec![];
+    let mut indices: Vec<u32> = vec![];
+    let count = text.chars().count();
+    let start = -(count.saturating_sub(1) as f32) * ADVANCE / 2.0;
+    for (n, c) in text.chars().enumerate() {
+        let Some(lit) = glyph(c) else {
+            continue;
+        };
+        let center = Vec2::new(start + n as f32 * ADVANCE, 0.0);
+        for (_, from, to) in SEGMENTS
+            .iter()
+            .filter(|(name, _, _)| lit.split(' ').any(|lit| lit == *name))
+        {
+            let from = Vec2::from(*from) + center;
+            let to = Vec2::from(*to) + center;
+            let along = (to - from).normalize() * STROKE / 2.0;
+            let across = along.perp();
+            let first = positions.len() as u32;
+            for corner in [
+                from - along - across,
+                to + along - across,
+                to + along + across,
+                from - along + across,
+            ] {
+                positions.push((corner * size).extend(0.0).into());
+            }
+            indices.extend([first, first + 1, first + 2, first, first + 2, first + 3]);
+        }
+    }
+    let normals = vec![[0.0, 0.0, 1.0]; positions.len()];
+    let uvs = vec![[0.0, 0.0]; positions.len()];
+    let colors = vec![color.to_linear().to_f32_array(); positions.len()];
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_indices(Indices::U32(indices));
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    mesh
+}
+
+/// Labels drawn past the arrow tips of a [`BevyTridentAxis`] on the same entity, facing the
+/// camera. Needs [`BevyTridentPlugin`](super::BevyTridentPlugin).
+///
+/// Letters, digits, `-` and `+` are drawn, anything else is left blank.
+#[derive(Component, Clone, Debug)]
+pub struct BevyTridentLabels {
+    /// Text of the X, Y and Z labels.
+    pub text: [String; 3],
+    /// Height of the characters.
+    pub size: f32,
+}
+
+impl Default for BevyTridentLabels {
+    fn default() -> Self {
+        Self::new(0.2)
+    }
+}
+
+impl BevyTridentLabels {
+    /// `X`, `Y` and `Z` labels of height `size`.
+    pub fn new(size: f32) -> Self {
+        Self {
+            text: ["X".into(), "Y".into(), "Z".into()],
+            size,
+        }
+    }
+
+    /// Replaces the label texts, e.g. `["E", "N", "U"]`.
+    pub fn with_text(mut self, text: [&str; 3]) -> Self {
+        self.text = text.map(String::from);
+        self
+    }
+}
+
+/// Label of an axis, child of the trident entity.
+#[derive(Component)]
+pub(crate) struct BevyTridentLabel;
+
+/// Respawns the labels of a trident whenever its axes or labels change.
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_labels(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    tridents: Query<
+        (
+            Entity,
+            &BevyTridentAxis,
+            &BevyTridentLabels,
+            Option<&RenderLayers>,
+        ),
+        Or<(Changed<BevyTridentAxis>, Changed<BevyTridentLabels>)>,
+    >,
+    labels: Query<(Entity, &Parent), With<BevyTridentLabel>>,
+) {
+    for (trident, axis, text, layers) in tridents.iter() {
+        for (label, parent) in labels.iter() {
+            if parent.get() == trident {
+                commands.entity(label).despawn_recursive();
+            }
+        }
+        let material = materials.add(StandardMaterial {
+            unlit: true,
+            ..default()
+        });
+        commands.entity(trident).with_children(|builder| {
+            for dir in 0..3 {
+                let arrow = axis.axises[dir];
+                let mut position = Vec3::ZERO;
+                position[dir] = arrow.tail_length + arrow.cone.height + text.size;
+                let mut label = builder.spawn((
+                    Mesh3d(meshes.add(label_mesh(&text.text[dir], text.size, axis.colors[dir]))),
+                    MeshMaterial3d(material.clone()),
+                    Transform::from_translation(position),
+                    BevyTridentLabel,
+                ));
+                if let Some(layers) = layers {
+                    label.insert(layers.clone());
+                }
+            }
+        });
+    }
+}
+
+/// Turns the labels towards the highest ordered active camera rendering them.
+///
+/// Runs after transform propagation and writes the `GlobalTransform` as well, so the labels do
+/// not lag a frame behind a trident rotated every frame.
+#[allow(clippy::type_complexity)]
+pub(crate) fn billboard_labels(
+    cameras: Query<(&Camera, &GlobalTransform, Option<&RenderLayers>), Without<BevyTridentLabel>>,
+    parents: Query<&GlobalTransform, Without<BevyTridentLabel>>,
+    mut labels: Query<
+        (
+            &Parent,
+            &mut Transform,
+            &mut GlobalTransform,
+            Option<&RenderLayers>,
+        ),
+        With<BevyTridentLabel>,
+    >,
+) {
+    let default_layers = RenderLayers::default();
+    for (parent, mut transform, mut global, layers) in labels.iter_mut() {
+        let layers = layers.unwrap_or(&default_layers);
+        let camera = cameras
+            .iter()
+            .filter(|(camera, _, camera_layers)| {
+                camera.is_active && camera_layers.unwrap_or(&default_layers).intersects(layers)
+            })
+            .max_by_key(|(camera, _, _)| camera.order);
+        let (Some((_, camera, _)), Ok(parent)) = (camera, parents.get(parent.get())) else {
+            continue;
+        };
+        transform.rotation = parent.rotation().inverse() * camera.rotation();
+        *global = parent.mul_transform(*transform);
+    }
+}
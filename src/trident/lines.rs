@@ -0,0 +1,64 @@
+use bevy::{
+    color::{Color, ColorToComponents},
+    math::Vec3,
+    render::{
+        mesh::{Indices, Mesh},
+        render_asset::RenderAssetUsages,
+        render_resource::PrimitiveTopology,
+    },
+};
+
+use super::BevyTridentAxis;
+
+/// `LineList` trident with the lengths and colours of a [`BevyTridentAxis`]: one line per axis
+/// with four arrowhead strokes of the cone's height and radius, and the negative stubs as plain
+/// lines.
+///
+/// The mesh has positions and vertex colours only, draw it with an unlit material.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BevyTridentLines(pub BevyTridentAxis);
+
+impl From<BevyTridentAxis> for BevyTridentLines {
+    fn from(trident: BevyTridentAxis) -> Self {
+        Self(trident)
+    }
+}
+
+impl From<BevyTridentLines> for Mesh {
+    fn from(lines: BevyTridentLines) -> Self {
+        let trident = lines.0;
+        let mut positions: Vec<[f32; 3]> = vec![];
+        let mut colors: Vec<[f32; 4]> = vec![];
+        let mut line = |from: Vec3, to: Vec3, color: Color| {
+            positions.extend([from.to_array(), to.to_array()]);
+            colors.extend([color.to_linear().to_f32_array(); 2]);
+        };
+
+        for dir in 0..3 {
+            let arrow = trident.axises[dir];
+            let color = trident.colors[dir];
+            let mut tip = Vec3::ZERO;
+            tip[dir] = arrow.tail_length + arrow.cone.height;
+            let mut base = Vec3::ZERO;
+            base[dir] = arrow.tail_length;
+            line(Vec3::ZERO, tip, color);
+            for side in [1, 2] {
+                let mut offset = Vec3::ZERO;
+                offset[(dir + side) % 3] = arrow.cone.radius;
+                line(tip, base + offset, color);
+                line(tip, base - offset, color);
+            }
+
+            if let Some(stubs) = trident.negative {
+                line(Vec3::ZERO, -tip * stubs.length, stubs.colors[dir]);
+            }
+        }
+
+        let indices = (0..positions.len() as u32).collect();
+        let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::default());
+        mesh.insert_indices(Indices::U32(indices));
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+        mesh
+    }
+}
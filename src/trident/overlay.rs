@@ -0,0 +1,139 @@
+use bevy::{
+    app::{App, Plugin, Startup, Update},
+    asset::Assets,
+    core_pipeline::core_3d::{Camera3d, Camera3dDepthLoadOp},
+    ecs::{
+        component::Component,
+        query::{With, Without},
+        system::{Commands, Query, Res, ResMut},
+    },
+    hierarchy::{BuildChildren, ChildBuild},
+    math::{Quat, Vec3},
+    pbr::{DirectionalLight, MeshMaterial3d, StandardMaterial},
+    prelude::{default, Mesh3d},
+    render::{
+        camera::{Camera, ClearColorConfig},
+        mesh::Mesh,
+        view::RenderLayers,
+    },
+    transform::components::Transform,
+    window::Window,
+};
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::{BevyTridentAxis, BevyTridentLabels};
+use crate::{viewcube::camera::BoundCamera, ViewcubeViewport};
+
+/// Render layer of the overlay, next to the viewcube's 13.
+const OVERLAY_LAYER: usize = 14;
+
+/// Corner axis indicator without the cube: a trident showing the world axes as seen by the
+/// camera marked with [`ViewcubeBinding`](crate::ViewcubeBinding), drawn where
+/// [`ViewcubeViewport`] places the small view.
+///
+/// Brings the label systems of [`BevyTridentPlugin`](super::BevyTridentPlugin) along, which can
+/// still be added before or after it.
+#[derive(Clone)]
+pub struct BevyTridentOverlayPlugin {
+    /// Shape and colours of the trident.
+    pub trident: BevyTridentAxis,
+    /// Labels past the arrow tips, `None` to draw none.
+    pub labels: Option<BevyTridentLabels>,
+}
+
+impl Default for BevyTridentOverlayPlugin {
+    fn default() -> Self {
+        Self {
+            trident: BevyTridentAxis::default(),
+            labels: Some(BevyTridentLabels::default()),
+        }
+    }
+}
+
+impl Plugin for BevyTridentOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        // The labels need the trident systems, without adding `BevyTridentPlugin` itself so it
+        // can still be added alongside
+        super::add_trident_systems(app);
+        let overlay = self.clone();
+        app.init_resource::<ViewcubeViewport>()
+            .add_systems(
+                Startup,
+                move |commands: Commands,
+                      meshes: ResMut<Assets<Mesh>>,
+                      materials: ResMut<Assets<StandardMaterial>>| {
+                    overlay.setup(commands, meshes, materials)
+                },
+            )
+            .add_systems(Update, update_overlay);
+    }
+}
+
+/// Camera drawing the overlay.
+#[derive(Component)]
+struct TridentOverlayView;
+
+/// Trident of the overlay.
+#[derive(Component)]
+struct TridentOverlay;
+
+impl BevyTridentOverlayPlugin {
+    fn setup(
+        &self,
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        commands
+            .spawn((
+                Camera3d {
+                    depth_load_op: Camera3dDepthLoadOp::Clear(0.),
+                    ..default()
+                },
+                Camera {
+                    order: 2,
+                    clear_color: ClearColorConfig::None,
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, 3.5).looking_at(Vec3::ZERO, Vec3::Y),
+                RenderLayers::layer(OVERLAY_LAYER),
+                TridentOverlayView,
+            ))
+            .with_children(|builder| {
+                builder.spawn((
+                    DirectionalLight::default(),
+                    Transform::default(),
+                    RenderLayers::layer(OVERLAY_LAYER),
+                ));
+            });
+
+        let mut trident = commands.spawn((
+            Mesh3d(meshes.add(self.trident)),
+            MeshMaterial3d(materials.add(StandardMaterial::default())),
+            Transform::default(),
+            RenderLayers::layer(OVERLAY_LAYER),
+            TridentOverlay,
+            self.trident,
+        ));
+        if let Some(labels) = &self.labels {
+            trident.insert(labels.clone());
+        }
+    }
+}
+
+/// Places the overlay camera and turns the trident against the bound camera.
+fn update_overlay(
+    windows: Query<&Window>,
+    viewport: Res<ViewcubeViewport>,
+    mut camera: Query<&mut Camera, With<TridentOverlayView>>,
+    bound_camera: BoundCamera,
+    mut trident: Query<&mut Transform, (With<TridentOverlay>, Without<PanOrbitCamera>)>,
+) {
+    let window: &Window = windows.single();
+    let mut cam = camera.single_mut();
+    cam.viewport = Some(viewport.viewport(window));
+    let rotation = bound_camera.rotation().unwrap_or(Quat::IDENTITY);
+    for mut transform in trident.iter_mut() {
+        transform.rotation = rotation.inverse();
+    }
+}
@@ -0,0 +1,118 @@
+use bevy::{
+    ecs::{component::Component, entity::Entity, query::Without, system::Query},
+    hierarchy::Parent,
+    math::Vec3,
+    render::{
+        camera::{Camera, Projection},
+        view::RenderLayers,
+    },
+    transform::components::{GlobalTransform, Transform},
+};
+
+use super::BevyTridentAxis;
+
+/// Rescales the trident on the same entity every frame so its longest arrow keeps the same
+/// length on screen, e.g. for a reference trident at the world origin. Needs
+/// [`BevyTridentPlugin`](super::BevyTridentPlugin).
+///
+/// Overwrites the scale of the `Transform`, which is then relative to the parent if any.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct BevyTridentScreenSize {
+    /// Length of the longest arrow in logical pixels.
+    pub pixels: f32,
+    /// Camera the size is kept for, `None` for the highest ordered active camera rendering the
+    /// trident.
+    pub camera: Option<Entity>,
+}
+
+impl Default for BevyTridentScreenSize {
+    fn default() -> Self {
+        Self::new(100.0)
+    }
+}
+
+impl BevyTridentScreenSize {
+    /// Arrows `pixels` long for the camera rendering the trident.
+    pub fn new(pixels: f32) -> Self {
+        Self {
+            pixels,
+            camera: None,
+        }
+    }
+
+    /// Keeps the size for `camera` instead.
+    pub fn with_camera(mut self, camera: Entity) -> Self {
+        self.camera = Some(camera);
+        self
+    }
+}
+
+/// Scales the tridents with a [`BevyTridentScreenSize`] from the projection of their camera and
+/// their depth in front of it.
+///
+/// Runs after transform propagation and writes the `GlobalTransform` as well, so the trident
+/// does not lag a frame behind the camera zooming.
+#[allow(clippy::type_complexity)]
+pub(crate) fn scale_tridents(
+    cameras: Query<
+        (
+            &Camera,
+            &GlobalTransform,
+            &Projection,
+            Option<&RenderLayers>,
+        ),
+        Without<BevyTridentScreenSize>,
+    >,
+    parents: Query<&GlobalTransform, Without<BevyTridentScreenSize>>,
+    mut tridents: Query<(
+        &BevyTridentScreenSize,
+        Option<&BevyTridentAxis>,
+        Option<&Parent>,
+        Option<&RenderLayers>,
+        &mut Transform,
+        &mut GlobalTransform,
+    )>,
+) {
+    let default_layers = RenderLayers::default();
+    for (size, axis, parent, layers, mut transform, mut global) in tridents.iter_mut() {
+        let layers = layers.unwrap_or(&default_layers);
+        let camera = match size.camera {
+            Some(entity) => cameras.get(entity).ok(),
+            None => cameras
+                .iter()
+                .filter(|(camera, _, _, camera_layers)| {
+                    camera.is_active && camera_layers.unwrap_or(&default_layers).intersects(layers)
+                })
+                .max_by_key(|(camera, _, _, _)| camera.order),
+        };
+        let Some((camera, camera_transform, projection, _)) = camera else {
+            continue;
+        };
+        let Some(viewport) = camera.logical_viewport_size() else {
+            continue;
+        };
+        // World units covered by one pixel at the depth of the trident
+        let unit = match projection {
+            Projection::Perspective(perspective) => {
+                let depth = (global.translation() - camera_transform.translation())
+                    .dot(*camera_transform.forward());
+                2.0 * depth.max(perspective.near) * (perspective.fov / 2.0).tan() / viewport.y
+            }
+            Projection::Orthographic(orthographic) => orthographic.area.height() / viewport.y,
+        };
+        let length = axis.map_or(1.0, |axis| {
+            axis.axises
+                .iter()
+                .map(|arrow| arrow.tail_length + arrow.cone.height)
+                .fold(0.0, f32::max)
+        });
+        if length <= 0.0 {
+            continue;
+        }
+        transform.scale = Vec3::splat(size.pixels * unit / length);
+        *global = match parent.and_then(|parent| parents.get(parent.get()).ok()) {
+            Some(parent) => parent.mul_transform(*transform),
+            None => GlobalTransform::from(*transform),
+        };
+    }
+}
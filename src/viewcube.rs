@@ -1,30 +1,65 @@
-//mod powerful_viewcube;
+mod axes;
+mod buttons;
+pub(crate) mod camera;
+mod history;
+mod magnet;
+mod outline;
+mod powerful_viewcube;
+mod settings;
 mod simple_viewcube;
+mod style;
+mod textures;
+mod views;
+
 use bevy::{
     app::{App, Plugin, Startup, Update},
+    asset::Assets,
+    color::{Alpha, Color},
     ecs::{
+        change_detection::{DetectChanges, DetectChangesMut, Ref},
         component::Component,
         entity::Entity,
-        query::With,
+        event::{Event, EventReader, EventWriter},
+        observer::Trigger,
+        query::{Has, Or, With, Without},
         schedule::IntoSystemConfigs,
-        system::{Commands, ParamSet, Query},
+        system::{Commands, Query, Res, ResMut, Resource},
+    },
+    math::{Quat, Vec3},
+    pbr::{MeshMaterial3d, StandardMaterial},
+    picking::{
+        events::{Click, Out, Over, Pointer},
+        PickingBehavior,
     },
-    math::{UVec2, Vec3},
-    prelude::default,
-    render::camera::Camera,
+    prelude::{AlphaMode, Visibility},
+    render::camera::{Camera, Projection},
+    time::Time,
     transform::components::Transform,
     window::Window,
 };
 use bevy_panorbit_camera::PanOrbitCamera;
-// use bevy_picking::prelude::Pickable;
-// use bevy_picking::mesh_picking::RayCastPickable;
-// use bevy_picking::prelude::*;
-// use bevy_picking::selection::On;
+use camera::BoundCamera;
+
+pub use buttons::ViewcubeLevel;
+pub use history::{ViewcubeHistory, ViewcubeHistoryStep, ViewcubeViewState};
+
+pub use settings::{
+    ViewcubeCornerView, ViewcubeEdgeView, ViewcubeMagnet, ViewcubeSettings, ViewcubeUpAxis,
+};
+pub use style::{ViewcubeOutline, ViewcubeStyle};
+pub use textures::{ViewcubeTexture, ViewcubeTextures};
+pub use views::{ViewcubeView, ViewcubeViews};
+
+use crate::PI_2;
 
-use crate::{PI_2, PI_4, PI_4_3};
+/// Edge length of the viewcube.
+pub(crate) const CUBE_SIZE: f32 = 0.8;
 
-#[derive(Copy, Clone, PartialEq, Eq)]
-pub(crate) enum CubePart {
+/// The 26 clickable parts of the viewcube: 6 faces, 12 edges and 8 corners.
+///
+/// The simple viewcube only has the faces.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CubePart {
     // Face
     Front,
     Back,
@@ -56,6 +91,120 @@ pub(crate) enum CubePart {
     BackRightBottom,
 }
 
+impl CubePart {
+    pub const ALL: [CubePart; 26] = [
+        CubePart::Front,
+        CubePart::Back,
+        CubePart::Left,
+        CubePart::Right,
+        CubePart::Top,
+        CubePart::Bottom,
+        CubePart::FrontTop,
+        CubePart::FrontBottom,
+        CubePart::BackTop,
+        CubePart::BackBottom,
+        CubePart::LeftTop,
+        CubePart::LeftBottom,
+        CubePart::RightTop,
+        CubePart::RightBottom,
+        CubePart::FrontLeft,
+        CubePart::FrontRight,
+        CubePart::BackLeft,
+        CubePart::BackRight,
+        CubePart::FrontLeftTop,
+        CubePart::FrontLeftBottom,
+        CubePart::FrontRightTop,
+        CubePart::FrontRightBottom,
+        CubePart::BackLeftTop,
+        CubePart::BackLeftBottom,
+        CubePart::BackRightTop,
+        CubePart::BackRightBottom,
+    ];
+
+    /// Yaw and pitch of the `PanOrbitCamera` looking from this part towards the cube center, in
+    /// the Y-up cube frame.
+    pub(crate) fn yaw_pitch(self, settings: &ViewcubeSettings) -> (f32, f32) {
+        let axes = self.axes();
+        // Turn from the front or back face towards the left or right one
+        let side_yaw = |angle: f32| {
+            if axes.z < 0.0 {
+                axes.x * (crate::PI - angle)
+            } else {
+                axes.x * angle
+            }
+        };
+        let heading = axes.x.atan2(axes.z);
+        if self.is_face() {
+            if axes.y != 0.0 {
+                (0.0, axes.y * PI_2)
+            } else {
+                (heading, 0.0)
+            }
+        } else if self.is_edge() {
+            let angle = settings.edge_view.angle();
+            if axes.y != 0.0 {
+                (heading, axes.y * angle)
+            } else {
+                (side_yaw(angle), 0.0)
+            }
+        } else {
+            let (yaw, pitch) = settings.corner_view.angles();
+            (side_yaw(yaw), axes.y * pitch)
+        }
+    }
+
+    /// Signs of the cube axes this part touches, e.g. `(0, 1, 1)` for [`CubePart::FrontTop`].
+    pub(crate) fn axes(self) -> Vec3 {
+        match self {
+            CubePart::Front => Vec3::Z,
+            CubePart::Back => Vec3::NEG_Z,
+            CubePart::Left => Vec3::NEG_X,
+            CubePart::Right => Vec3::X,
+            CubePart::Top => Vec3::Y,
+            CubePart::Bottom => Vec3::NEG_Y,
+            CubePart::FrontTop => Vec3::new(0.0, 1.0, 1.0),
+            CubePart::FrontBottom => Vec3::new(0.0, -1.0, 1.0),
+            CubePart::BackTop => Vec3::new(0.0, 1.0, -1.0),
+            CubePart::BackBottom => Vec3::new(0.0, -1.0, -1.0),
+            CubePart::LeftTop => Vec3::new(-1.0, 1.0, 0.0),
+            CubePart::LeftBottom => Vec3::new(-1.0, -1.0, 0.0),
+            CubePart::RightTop => Vec3::new(1.0, 1.0, 0.0),
+            CubePart::RightBottom => Vec3::new(1.0, -1.0, 0.0),
+            CubePart::FrontLeft => Vec3::new(-1.0, 0.0, 1.0),
+            CubePart::FrontRight => Vec3::new(1.0, 0.0, 1.0),
+            CubePart::BackLeft => Vec3::new(-1.0, 0.0, -1.0),
+            CubePart::BackRight => Vec3::new(1.0, 0.0, -1.0),
+            CubePart::FrontLeftTop => Vec3::new(-1.0, 1.0, 1.0),
+            CubePart::FrontLeftBottom => Vec3::new(-1.0, -1.0, 1.0),
+            CubePart::FrontRightTop => Vec3::new(1.0, 1.0, 1.0),
+            CubePart::FrontRightBottom => Vec3::new(1.0, -1.0, 1.0),
+            CubePart::BackLeftTop => Vec3::new(-1.0, 1.0, -1.0),
+            CubePart::BackLeftBottom => Vec3::new(-1.0, -1.0, -1.0),
+            CubePart::BackRightTop => Vec3::new(1.0, 1.0, -1.0),
+            CubePart::BackRightBottom => Vec3::new(1.0, -1.0, -1.0),
+        }
+    }
+
+    pub fn is_face(self) -> bool {
+        self.axes().abs().element_sum() == 1.0
+    }
+
+    pub fn is_edge(self) -> bool {
+        self.axes().abs().element_sum() == 2.0
+    }
+
+    pub fn is_corner(self) -> bool {
+        self.axes().abs().element_sum() == 3.0
+    }
+
+    /// Unit vector from the cube center towards the camera when looking from this part, in the
+    /// Y-up cube frame.
+    pub(crate) fn direction(self, settings: &ViewcubeSettings) -> Vec3 {
+        let (yaw, pitch) = self.yaw_pitch(settings);
+        camera::orbit_direction(yaw, pitch)
+    }
+}
+
 #[derive(Default)]
 pub struct BevyViewCubePlugin {
     pub use_powerful_viewcube: bool,
@@ -63,18 +212,170 @@ pub struct BevyViewCubePlugin {
 
 impl Plugin for BevyViewCubePlugin {
     fn build(&self, app: &mut App) {
-        let setup = simple_viewcube::setup;
-        app.add_systems(Startup, (setup, crate::create_small_view).chain())
+        textures::embed_default_textures(app);
+        if self.use_powerful_viewcube {
+            app.insert_resource(ViewcubeKind::Powerful).add_systems(
+                Startup,
+                (
+                    powerful_viewcube::setup,
+                    crate::create_small_view,
+                    buttons::setup,
+                )
+                    .chain(),
+            );
+        } else {
+            app.insert_resource(ViewcubeKind::Simple).add_systems(
+                Startup,
+                (
+                    simple_viewcube::setup,
+                    crate::create_small_view,
+                    buttons::setup,
+                )
+                    .chain(),
+            );
+        }
+        app.init_resource::<ViewcubeSettings>()
+            .init_resource::<crate::ViewcubeViewport>()
+            .init_resource::<ViewcubeStyle>()
+            .init_resource::<ViewcubeTextures>()
+            .init_resource::<ViewcubeViews>()
+            .init_resource::<ViewcubeFade>()
+            .add_event::<ViewcubeSnap>()
+            .add_event::<ViewcubeLevel>()
+            .add_event::<ViewcubeHistoryStep>()
             .add_systems(Update, update_view)
-            .add_systems(Update, viewcube_hit);
+            .add_systems(Update, fade_view.after(update_view))
+            .add_systems(Update, magnet::magnetic_snap.before(snap_view))
+            .add_systems(Update, history::init_history)
+            .add_systems(
+                Update,
+                (snap_view, buttons::level_view, history::step_history).chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    views::update_markers,
+                    update_active_part,
+                    update_disabled_parts,
+                    buttons::update_history_buttons,
+                    buttons::update_buttons,
+                    outline::update_outline,
+                    textures::update_textures,
+                    update_materials,
+                )
+                    .chain()
+                    .after(fade_view),
+            );
     }
 }
 
 #[derive(Component)]
 pub(crate) struct ViewcubeCenter;
 
+/// Trident inside the viewcube, kept aligned with the world axes.
 #[derive(Component)]
-pub(crate) struct ViewcubeHit(pub CubePart);
+pub(crate) struct ViewcubeTrident;
+
+/// Snaps the bound camera to a view, sent when a part or a view marker is clicked.
+///
+/// Can be sent from code as well.
+#[derive(Event, Clone, Debug)]
+pub enum ViewcubeSnap {
+    /// Look from a part of the cube.
+    Part(CubePart),
+    /// Look from the view of [`ViewcubeViews`] with this name.
+    View(String),
+    /// Look from any view.
+    To(ViewcubeView),
+}
+
+impl ViewcubeSnap {
+    /// The view to snap to, `None` if it names an unknown view.
+    pub(crate) fn view(
+        &self,
+        settings: &ViewcubeSettings,
+        views: &ViewcubeViews,
+    ) -> Option<ViewcubeView> {
+        match self {
+            ViewcubeSnap::Part(part) => Some(ViewcubeView::new(
+                format!("{part:?}"),
+                part.direction(settings),
+            )),
+            ViewcubeSnap::View(name) => views.get(name).cloned(),
+            ViewcubeSnap::To(view) => Some(view.clone()),
+        }
+    }
+}
+
+/// Mesh drawn in the viewcube view, tinted and faded by `update_materials`.
+#[derive(Component)]
+pub(crate) struct ViewcubeMesh;
+
+/// Which cube [`BevyViewCubePlugin`] spawned.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum ViewcubeKind {
+    /// Six faces meeting at sharp edges.
+    Simple,
+    /// Smaller faces with bevelled edges and corners between them.
+    Powerful,
+}
+
+impl ViewcubeKind {
+    /// Width of the bevel along the edges, along each cube axis.
+    pub(crate) fn bevel(self) -> f32 {
+        match self {
+            ViewcubeKind::Simple => 0.0,
+            ViewcubeKind::Powerful => powerful_viewcube::BEVEL,
+        }
+    }
+}
+
+/// Current opacity of the viewcube and how long the bound camera has been still.
+#[derive(Resource)]
+pub(crate) struct ViewcubeFade {
+    opacity: f32,
+    idle_secs: f32,
+}
+
+impl Default for ViewcubeFade {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            idle_secs: 0.0,
+        }
+    }
+}
+
+/// Clickable part of the viewcube.
+#[derive(Component)]
+pub(crate) struct ViewcubePart(pub CubePart);
+
+/// Pointer and camera state of a [`ViewcubePart`], turned into a tint by
+/// `update_materials`.
+#[derive(Component, Default)]
+pub(crate) struct ViewcubePartState {
+    pub hovered: bool,
+    pub pressed: bool,
+    pub active: bool,
+    pub disabled: bool,
+}
+
+impl ViewcubePartState {
+    /// Tint of a part or view marker which is `idle` when not interacted with.
+    fn color(&self, idle: Color, style: &ViewcubeStyle) -> Color {
+        if self.pressed {
+            style.pressed_color
+        } else if self.hovered {
+            style.hover_color
+        } else if self.disabled {
+            style.disabled_color
+        } else if self.active {
+            style.active_color
+        } else {
+            idle
+        }
+    }
+}
 
 #[macro_export]
 macro_rules! generate_viewcube_face {
@@ -84,95 +385,382 @@ macro_rules! generate_viewcube_face {
             MeshMaterial3d($materials.add(StandardMaterial::from($color))),
             $transform,
             RenderLayers::layer(13),
-            // RaycastPickable,
+            $component,
+            $crate::viewcube::ViewcubePartState::default(),
+            $crate::viewcube::ViewcubeMesh,
         )
     };
 }
 
-// Then add this function
-// fn debug_picking(pointers: Query<&bevy_picking::prelude::Pointer<bevy_picking::prelude::Move>>) {
-//     // println!("pointers: {:?}", pointers);
-//     // println!("number of pointers: {:?}", pointers.iter().count());
-//     for pointer in &pointers {
-//         println!("Pointer detected over: {:?}", pointer.target);
-//     }
-// }
+pub(crate) fn on_part_click(
+    trigger: Trigger<Pointer<Click>>,
+    mut parts: Query<(&ViewcubePart, &mut ViewcubePartState)>,
+    mut snaps: EventWriter<ViewcubeSnap>,
+) {
+    let Ok((part, mut state)) = parts.get_mut(trigger.entity()) else {
+        return;
+    };
+    state.pressed = true;
+    snaps.send(ViewcubeSnap::Part(part.0));
+}
 
+pub(crate) fn on_part_over(
+    trigger: Trigger<Pointer<Over>>,
+    mut parts: Query<&mut ViewcubePartState>,
+) {
+    if let Ok(mut state) = parts.get_mut(trigger.entity()) {
+        state.hovered = true;
+    }
+}
+
+pub(crate) fn on_part_out(
+    trigger: Trigger<Pointer<Out>>,
+    mut parts: Query<&mut ViewcubePartState>,
+) {
+    if let Ok(mut state) = parts.get_mut(trigger.entity()) {
+        state.hovered = false;
+        state.pressed = false;
+    }
+}
+
+#[allow(clippy::type_complexity)]
 pub(crate) fn update_view(
     windows: Query<&Window>,
+    settings: Res<ViewcubeSettings>,
+    viewport: Res<crate::ViewcubeViewport>,
     mut camera: Query<&mut Camera, With<crate::SmallView>>,
-    mut transform_query: ParamSet<(
-        Query<&mut Transform, With<ViewcubeCenter>>,
-        Query<&Transform, (With<PanOrbitCamera>, With<crate::ViewcubeBinding>)>,
-    )>,
+    bound_camera: BoundCamera,
+    mut center: Query<&mut Transform, (With<ViewcubeCenter>, Without<PanOrbitCamera>)>,
+    mut trident: Query<
+        &mut Transform,
+        (
+            With<ViewcubeTrident>,
+            Without<ViewcubeCenter>,
+            Without<PanOrbitCamera>,
+        ),
+    >,
 ) {
     let window: &Window = windows.single();
     let mut cam = camera.single_mut();
-    cam.viewport = Some(bevy::render::camera::Viewport {
-        physical_position: UVec2::new(0, (window.physical_height() as f32 * 0.7) as u32),
-        physical_size: UVec2::new(
-            (window.physical_width() as f32 * 0.2) as u32,
-            (window.physical_height() as f32 * 0.3) as u32,
-        ),
-        ..default()
+    cam.viewport = Some(viewport.viewport(window));
+    let rotation = bound_camera.rotation().unwrap_or(Quat::IDENTITY);
+    let frame = bound_camera.cube_rotation(&settings);
+    let mut center_transform = center.single_mut();
+    center_transform.rotation = rotation.inverse() * frame;
+    // The cube is drawn in the Y-up frame, the trident shows the world axes
+    for mut trident_transform in trident.iter_mut() {
+        trident_transform.rotation = frame.inverse();
+    }
+}
+
+/// Marks the part the bound camera is looking from, within [`ViewcubeStyle::active_tolerance`].
+pub(crate) fn update_active_part(
+    style: Res<ViewcubeStyle>,
+    settings: Res<ViewcubeSettings>,
+    bound_camera: BoundCamera,
+    views: Res<ViewcubeViews>,
+    mut parts: Query<(&ViewcubePart, &mut ViewcubePartState)>,
+    mut markers: Query<(&views::ViewcubeViewMarker, &mut ViewcubePartState), Without<ViewcubePart>>,
+) {
+    let looking_from = bound_camera.rotation().map(|rotation| {
+        let frame = bound_camera.cube_rotation(&settings);
+        frame.inverse() * rotation * Vec3::Z
     });
-    let transform;
-    {
-        let orbit_cameras = transform_query.p1();
-        transform = if let Ok(tr) = orbit_cameras.get_single() {
-            tr.clone()
-        } else {
-            Transform::IDENTITY
+    let is_near = |direction: Vec3| {
+        looking_from.is_some_and(|looking_from| {
+            direction.angle_between(looking_from) <= style.active_tolerance
+        })
+    };
+    let active = CubePart::ALL
+        .into_iter()
+        .find(|part| is_near(part.direction(&settings)));
+    for (part, mut state) in parts.iter_mut() {
+        let is_active = active == Some(part.0);
+        if state.active != is_active {
+            state.active = is_active;
+        }
+    }
+    for (marker, mut state) in markers.iter_mut() {
+        let is_active = views
+            .views
+            .get(marker.0)
+            .is_some_and(|view| is_near(view.direction));
+        if state.active != is_active {
+            state.active = is_active;
         }
     }
-    let mut trident = transform_query.p0();
-    let mut trident_transform = trident.single_mut();
-    trident_transform.rotation = transform.rotation.inverse();
 }
 
-pub(crate) fn viewcube_hit(
+/// Draws the parts turned off in [`ViewcubeSettings::disabled_parts`], and the parts and view
+/// markers the bound camera cannot reach within its yaw and pitch limits, as disabled. Parts
+/// turned off also stop being pickable.
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_disabled_parts(
     mut commands: Commands,
-    entity: Query<(Entity, &ViewcubeHit)>,
-    mut camera: Query<&mut PanOrbitCamera>,
+    settings: Res<ViewcubeSettings>,
+    views: Res<ViewcubeViews>,
+    bound_camera: BoundCamera,
+    camera: Query<&PanOrbitCamera, With<crate::ViewcubeBinding>>,
+    mut parts: Query<
+        (
+            Entity,
+            Option<&ViewcubePart>,
+            Option<&views::ViewcubeViewMarker>,
+            &mut ViewcubePartState,
+        ),
+        Or<(With<ViewcubePart>, With<views::ViewcubeViewMarker>)>,
+    >,
 ) {
-    if entity.is_empty() {
-        return;
+    let camera = camera.get_single().ok();
+    let is_reachable = |direction: Vec3| {
+        camera.is_none_or(|camera| {
+            bound_camera
+                .orbit_angles(&settings, direction)
+                .is_some_and(|(yaw, pitch)| camera::limit_angles(camera, yaw, pitch).2)
+        })
+    };
+    for (entity, part, marker, mut state) in parts.iter_mut() {
+        let is_enabled = part.is_none_or(|part| settings.is_enabled(part.0));
+        if part.is_some() && settings.is_changed() {
+            let picking = if is_enabled {
+                PickingBehavior::default()
+            } else {
+                PickingBehavior::IGNORE
+            };
+            commands.entity(entity).insert(picking);
+            if !is_enabled {
+                state.hovered = false;
+                state.pressed = false;
+            }
+        }
+
+        let direction = match (part, marker) {
+            (Some(part), _) => Some(part.0.direction(&settings)),
+            (None, Some(marker)) => views.views.get(marker.0).map(|view| view.direction),
+            _ => None,
+        };
+        let is_disabled =
+            !is_enabled || direction.is_some_and(|direction| !is_reachable(direction));
+        if state.disabled != is_disabled {
+            state.disabled = is_disabled;
+        }
+    }
+}
+
+/// Whether the cursor is over the viewport of the viewcube.
+pub(crate) fn cursor_over_view(
+    windows: &Query<&Window>,
+    camera: &Query<&Camera, With<crate::SmallView>>,
+) -> bool {
+    match (windows.get_single(), camera.get_single()) {
+        (Ok(window), Ok(cam)) => window
+            .cursor_position()
+            .zip(cam.logical_viewport_rect())
+            .is_some_and(|(cursor, rect)| rect.contains(cursor)),
+        _ => false,
+    }
+}
+
+/// Fades the viewcube between [`ViewcubeStyle::idle_opacity`] and fully opaque while the pointer
+/// is over its viewport, and out completely after [`ViewcubeStyle::hide_after`] seconds of
+/// camera stillness.
+#[allow(clippy::type_complexity)]
+pub(crate) fn fade_view(
+    time: Res<Time>,
+    style: Res<ViewcubeStyle>,
+    mut fade: ResMut<ViewcubeFade>,
+    windows: Query<&Window>,
+    camera: Query<&Camera, With<crate::SmallView>>,
+    orbit_cameras: Query<Ref<Transform>, camera::BoundCameraFilter>,
+    mut shown: Query<&mut Visibility, Or<(With<ViewcubeCenter>, With<buttons::ViewcubeButton>)>>,
+) {
+    let hovered = cursor_over_view(&windows, &camera);
+    let moved = orbit_cameras
+        .get_single()
+        .is_ok_and(|transform| transform.is_changed());
+
+    if hovered || moved {
+        fade.idle_secs = 0.0;
+    } else {
+        fade.idle_secs += time.delta_secs();
+    }
+
+    let target = if hovered {
+        1.0
+    } else if style.hide_after.is_some_and(|secs| fade.idle_secs >= secs) {
+        0.0
+    } else {
+        style.idle_opacity.clamp(0.0, 1.0)
+    };
+    let step = style.fade_speed * time.delta_secs();
+    let opacity = if fade.opacity < target {
+        (fade.opacity + step).min(target)
+    } else {
+        (fade.opacity - step).max(target)
+    };
+    if fade.opacity != opacity {
+        fade.opacity = opacity;
     }
-    let (item, dir) = entity.single();
-    commands.entity(item).remove::<ViewcubeHit>();
-
-    let (alpha, beta) = match dir.0 {
-        CubePart::Right => (PI_2, 0.0),
-        CubePart::Left => (-PI_2, 0.0),
-        CubePart::Top => (0.0, PI_2),
-        CubePart::Bottom => (0.0, -PI_2),
-        CubePart::Front => (0.0, 0.0),
-        CubePart::Back => (crate::PI, 0.0),
-        CubePart::FrontTop => (0.0, PI_4),
-        CubePart::FrontBottom => (0.0, -PI_4),
-        CubePart::BackTop => (crate::PI, PI_4),
-        CubePart::BackBottom => (crate::PI, -PI_4),
-        CubePart::LeftTop => (-PI_2, PI_4),
-        CubePart::LeftBottom => (-PI_2, -PI_4),
-        CubePart::RightTop => (PI_2, PI_4),
-        CubePart::RightBottom => (PI_2, -PI_4),
-        CubePart::FrontLeft => (-PI_4, 0.0),
-        CubePart::FrontRight => (PI_4, 0.0),
-        CubePart::BackLeft => (-PI_4_3, 0.0),
-        CubePart::BackRight => (PI_4_3, 0.0),
-        CubePart::FrontLeftTop => (-PI_4, PI_4),
-        CubePart::FrontLeftBottom => (-PI_4, -PI_4),
-        CubePart::FrontRightTop => (PI_4, PI_4),
-        CubePart::FrontRightBottom => (PI_4, -PI_4),
-        CubePart::BackLeftTop => (-PI_4_3, PI_4),
-        CubePart::BackLeftBottom => (-PI_4_3, -PI_4),
-        CubePart::BackRightTop => (PI_4_3, PI_4),
-        CubePart::BackRightBottom => (PI_4_3, -PI_4),
+
+    let visibility = if opacity > 0.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
     };
+    for mut shown in shown.iter_mut() {
+        shown.set_if_neq(visibility);
+    }
+}
 
-    let mut orbit_camera = camera.single_mut();
+/// Applies the part tints and the current fade to every viewcube material.
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_materials(
+    style: Res<ViewcubeStyle>,
+    fade: Res<ViewcubeFade>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    meshes: Query<
+        (
+            Option<&ViewcubePart>,
+            Option<Ref<ViewcubePartState>>,
+            Has<buttons::ViewcubeButton>,
+            Has<axes::ViewcubeTridentAxis>,
+            Has<outline::ViewcubeOutlineMesh>,
+            Ref<MeshMaterial3d<StandardMaterial>>,
+        ),
+        With<ViewcubeMesh>,
+    >,
+) {
+    for (part, state, is_button, is_axis, is_outline, material) in meshes.iter() {
+        let state_changed = state.as_ref().is_some_and(|state| state.is_changed());
+        if !style.is_changed() && !fade.is_changed() && !state_changed && !material.is_changed() {
+            continue;
+        }
+        if let Some(material) = materials.get_mut(&material.0) {
+            let color = match (state, part, style.outline) {
+                (Some(state), Some(part), _) => state.color(style.part_color(part.0), &style),
+                (Some(state), None, _) if is_button => state.color(style.button_color, &style),
+                // The trident keeps its vertex colours unless interacted with
+                (Some(state), None, _) if is_axis => state.color(Color::WHITE, &style),
+                (Some(state), None, _) => state.color(style.view_color, &style),
+                (None, _, Some(outline)) if is_outline => outline.color,
+                _ => Color::WHITE,
+            };
+            material.base_color = color.with_alpha(fade.opacity);
+            material.alpha_mode = if fade.opacity < 1.0 {
+                AlphaMode::Blend
+            } else {
+                AlphaMode::Opaque
+            };
+        }
+    }
+}
 
-    // Keep the current focus point instead of resetting to origin
-    orbit_camera.target_yaw = alpha;
-    orbit_camera.target_pitch = beta;
+/// Moves the bound camera to the view of each [`ViewcubeSnap`].
+#[allow(clippy::type_complexity)]
+pub(crate) fn snap_view(
+    mut snaps: EventReader<ViewcubeSnap>,
+    settings: Res<ViewcubeSettings>,
+    views: Res<ViewcubeViews>,
+    bound_camera: BoundCamera,
+    mut camera: Query<
+        (
+            &mut PanOrbitCamera,
+            Option<&mut ViewcubeHistory>,
+            Option<&Projection>,
+        ),
+        With<crate::ViewcubeBinding>,
+    >,
+) {
+    for snap in snaps.read() {
+        let (Some(view), Ok((mut orbit_camera, history, projection))) =
+            (snap.view(&settings, &views), camera.get_single_mut())
+        else {
+            continue;
+        };
+        // A view without a direction would turn the camera to NaN angles
+        let Some((yaw, pitch)) = bound_camera.orbit_angles(&settings, view.direction) else {
+            continue;
+        };
+        if let Some(mut history) = history {
+            history.push(ViewcubeViewState::of(&orbit_camera, projection));
+        }
+
+        let (yaw, pitch, _) = camera::limit_angles(&orbit_camera, yaw, pitch);
+
+        // Keep the current focus point and distance unless the view has its own
+        orbit_camera.target_yaw = yaw;
+        orbit_camera.target_pitch = pitch;
+        if let Some(radius) = view.radius {
+            orbit_camera.target_radius = radius.clamp(
+                orbit_camera.zoom_lower_limit,
+                orbit_camera.zoom_upper_limit.unwrap_or(f32::MAX),
+            );
+        }
+        if let Some(focus) = view.focus {
+            orbit_camera.target_focus = focus;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::*;
+    use crate::PI_4;
+
+    #[test]
+    fn degrees_45_matches_the_baseline_table() {
+        let pi_4_3 = PI / 4.0 * 3.0;
+        let table = [
+            (CubePart::Right, (PI_2, 0.0)),
+            (CubePart::Left, (-PI_2, 0.0)),
+            (CubePart::Top, (0.0, PI_2)),
+            (CubePart::Bottom, (0.0, -PI_2)),
+            (CubePart::Front, (0.0, 0.0)),
+            (CubePart::Back, (PI, 0.0)),
+            (CubePart::FrontTop, (0.0, PI_4)),
+            (CubePart::FrontBottom, (0.0, -PI_4)),
+            (CubePart::BackTop, (PI, PI_4)),
+            (CubePart::BackBottom, (PI, -PI_4)),
+            (CubePart::LeftTop, (-PI_2, PI_4)),
+            (CubePart::LeftBottom, (-PI_2, -PI_4)),
+            (CubePart::RightTop, (PI_2, PI_4)),
+            (CubePart::RightBottom, (PI_2, -PI_4)),
+            (CubePart::FrontLeft, (-PI_4, 0.0)),
+            (CubePart::FrontRight, (PI_4, 0.0)),
+            (CubePart::BackLeft, (-pi_4_3, 0.0)),
+            (CubePart::BackRight, (pi_4_3, 0.0)),
+            (CubePart::FrontLeftTop, (-PI_4, PI_4)),
+            (CubePart::FrontLeftBottom, (-PI_4, -PI_4)),
+            (CubePart::FrontRightTop, (PI_4, PI_4)),
+            (CubePart::FrontRightBottom, (PI_4, -PI_4)),
+            (CubePart::BackLeftTop, (-pi_4_3, PI_4)),
+            (CubePart::BackLeftBottom, (-pi_4_3, -PI_4)),
+            (CubePart::BackRightTop, (pi_4_3, PI_4)),
+            (CubePart::BackRightBottom, (pi_4_3, -PI_4)),
+        ];
+        assert_eq!(table.len(), CubePart::ALL.len());
+        let settings = ViewcubeSettings::default();
+        for (part, (yaw, pitch)) in table {
+            let (actual_yaw, actual_pitch) = part.yaw_pitch(&settings);
+            assert!(
+                (actual_yaw - yaw).abs() < 1e-6 && (actual_pitch - pitch).abs() < 1e-6,
+                "{part:?}: ({actual_yaw}, {actual_pitch}) instead of ({yaw}, {pitch})"
+            );
+        }
+    }
+
+    #[test]
+    fn part_directions_point_at_their_part() {
+        // Corners only point at their corner from the isometric angle
+        let settings = ViewcubeSettings {
+            corner_view: ViewcubeCornerView::Isometric,
+            ..Default::default()
+        };
+        for part in CubePart::ALL {
+            let angle = part.direction(&settings).angle_between(part.axes());
+            assert!(angle < 1e-5, "{part:?} is {angle} off");
+        }
+    }
 }
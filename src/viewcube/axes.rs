@@ -0,0 +1,75 @@
+use bevy::{prelude::*, render::view::RenderLayers};
+
+use super::{
+    camera::BoundCamera, on_part_out, on_part_over, ViewcubeMesh, ViewcubePartState,
+    ViewcubeSettings, ViewcubeSnap, ViewcubeTrident, ViewcubeView,
+};
+use crate::prelude::{BevyTridentAxis, BevyTridentDirection};
+
+/// Arrow of the trident inside the viewcube along this axis, 0 to 2 for X to Z.
+#[derive(Component)]
+pub(crate) struct ViewcubeTridentAxis(pub usize);
+
+/// Spawns the trident inside the viewcube, one pickable mesh per arrow, at `translation` from
+/// the cube center.
+pub(crate) fn spawn_trident(
+    builder: &mut ChildBuilder,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    translation: Vec3,
+) {
+    let trident = BevyTridentAxis::default();
+    builder
+        .spawn((
+            Mesh3d(meshes.add(trident.origin_mesh())),
+            MeshMaterial3d(materials.add(StandardMaterial::default())),
+            Transform::from_translation(translation),
+            RenderLayers::layer(13),
+            ViewcubeMesh,
+            ViewcubeTrident,
+        ))
+        .with_children(|builder| {
+            for dir in BevyTridentDirection::ALL {
+                builder
+                    .spawn((
+                        Mesh3d(meshes.add(trident.axis_mesh(dir))),
+                        MeshMaterial3d(materials.add(StandardMaterial::default())),
+                        Transform::default(),
+                        RenderLayers::layer(13),
+                        ViewcubeTridentAxis(dir.index()),
+                        ViewcubePartState::default(),
+                        ViewcubeMesh,
+                    ))
+                    .observe(on_axis_click)
+                    .observe(on_part_over)
+                    .observe(on_part_out);
+            }
+        });
+}
+
+/// Looks down the clicked axis from its positive side, or from its negative side while
+/// [`ViewcubeSettings::opposite_key`] is held.
+fn on_axis_click(
+    trigger: Trigger<Pointer<Click>>,
+    settings: Res<ViewcubeSettings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    bound_camera: BoundCamera,
+    mut axes: Query<(&ViewcubeTridentAxis, &mut ViewcubePartState)>,
+    mut snaps: EventWriter<ViewcubeSnap>,
+) {
+    let Ok((axis, mut state)) = axes.get_mut(trigger.entity()) else {
+        return;
+    };
+    state.pressed = true;
+    let opposite = settings.opposite_key.is_some_and(|key| keys.pressed(key));
+    let mut direction = Vec3::ZERO;
+    direction[axis.0] = if opposite { -1.0 } else { 1.0 };
+    // The trident shows the axes of the frame the cube is rotated to
+    let direction = bound_camera.cube_rotation(&settings).inverse() * direction;
+    let name = format!(
+        "{}{}",
+        if opposite { "-" } else { "+" },
+        ["X", "Y", "Z"][axis.0]
+    );
+    snaps.send(ViewcubeSnap::To(ViewcubeView::new(name, direction)));
+}
@@ -0,0 +1,216 @@
+use bevy::{prelude::*, render::view::RenderLayers};
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::{
+    history::{ViewcubeHistory, ViewcubeHistoryStep, ViewcubeViewState},
+    on_part_out, on_part_over, ViewcubeMesh, ViewcubePartState, ViewcubeStyle,
+};
+use crate::{PI, PI_2};
+
+/// Radius of the buttons drawn next to the cube.
+const BUTTON_RADIUS: f32 = 0.15;
+
+/// Button drawn next to the cube, fixed in the small view whatever the camera orientation.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum ViewcubeButton {
+    /// Sends [`ViewcubeLevel`].
+    Level,
+    /// Sends [`ViewcubeHistoryStep::Back`], shown with [`ViewcubeStyle::history_buttons`].
+    Back,
+    /// Sends [`ViewcubeHistoryStep::Forward`], shown with [`ViewcubeStyle::history_buttons`].
+    Forward,
+}
+
+impl ViewcubeButton {
+    /// Position in the frame of the small view camera, the cube is seen at `(-0.6, -0.6, -4.0)`.
+    fn position(self) -> Vec3 {
+        match self {
+            ViewcubeButton::Level => Vec3::new(0.9, 0.9, -4.0),
+            ViewcubeButton::Back => Vec3::new(0.2, 0.9, -4.0),
+            ViewcubeButton::Forward => Vec3::new(0.5, 0.9, -4.0),
+        }
+    }
+
+    fn mesh(self) -> Mesh {
+        let r = BUTTON_RADIUS;
+        match self {
+            ViewcubeButton::Level => Circle::new(r).into(),
+            ViewcubeButton::Back => Triangle2d::new(
+                Vec2::new(-r, 0.0),
+                Vec2::new(0.6 * r, -r),
+                Vec2::new(0.6 * r, r),
+            )
+            .into(),
+            ViewcubeButton::Forward => Triangle2d::new(
+                Vec2::new(r, 0.0),
+                Vec2::new(-0.6 * r, r),
+                Vec2::new(-0.6 * r, -r),
+            )
+            .into(),
+        }
+    }
+}
+
+/// Brings the bound camera back upright while keeping its heading, sent when the level button
+/// is clicked.
+///
+/// `PanOrbitCamera` has no roll of its own: with `allow_upside_down` the camera is only ever
+/// rolled by pitching past the poles, so leveling turns the heading around and mirrors the pitch
+/// back between -90° and 90°, looking in the same direction.
+#[derive(Event, Clone, Copy, Debug, Default)]
+pub struct ViewcubeLevel;
+
+fn spawn_button(
+    builder: &mut ChildBuilder,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    button: ViewcubeButton,
+) {
+    builder
+        .spawn((
+            Mesh3d(meshes.add(button.mesh())),
+            MeshMaterial3d(materials.add(StandardMaterial::default())),
+            Transform::from_translation(button.position()),
+            RenderLayers::layer(13),
+            button,
+            ViewcubePartState::default(),
+            ViewcubeMesh,
+        ))
+        .observe(on_button_click)
+        .observe(on_part_over)
+        .observe(on_part_out);
+}
+
+/// Spawns the level button as a child of the small view camera.
+pub(crate) fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    camera: Query<Entity, With<crate::SmallView>>,
+) {
+    let Ok(camera) = camera.get_single() else {
+        return;
+    };
+    commands.entity(camera).with_children(|builder| {
+        spawn_button(builder, &mut meshes, &mut materials, ViewcubeButton::Level);
+    });
+}
+
+/// Adds or removes the arrow buttons whenever [`ViewcubeStyle::history_buttons`] changes.
+pub(crate) fn update_history_buttons(
+    mut commands: Commands,
+    style: Res<ViewcubeStyle>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    camera: Query<Entity, With<crate::SmallView>>,
+    buttons: Query<(Entity, &ViewcubeButton)>,
+) {
+    if !style.is_changed() {
+        return;
+    }
+    let shown = buttons
+        .iter()
+        .filter(|(_, button)| **button != ViewcubeButton::Level)
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+    match (style.history_buttons, shown.is_empty(), camera.get_single()) {
+        (true, true, Ok(camera)) => {
+            commands.entity(camera).with_children(|builder| {
+                for button in [ViewcubeButton::Back, ViewcubeButton::Forward] {
+                    spawn_button(builder, &mut meshes, &mut materials, button);
+                }
+            });
+        }
+        (false, false, _) => {
+            for entity in shown {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn on_button_click(
+    trigger: Trigger<Pointer<Click>>,
+    mut buttons: Query<(&ViewcubeButton, &mut ViewcubePartState)>,
+    mut levels: EventWriter<ViewcubeLevel>,
+    mut steps: EventWriter<ViewcubeHistoryStep>,
+) {
+    let Ok((button, mut state)) = buttons.get_mut(trigger.entity()) else {
+        return;
+    };
+    state.pressed = true;
+    match button {
+        ViewcubeButton::Level => {
+            levels.send(ViewcubeLevel);
+        }
+        ViewcubeButton::Back => {
+            steps.send(ViewcubeHistoryStep::Back);
+        }
+        ViewcubeButton::Forward => {
+            steps.send(ViewcubeHistoryStep::Forward);
+        }
+    }
+}
+
+/// Pitch wrapped to `(-PI, PI]`.
+fn wrap_pitch(pitch: f32) -> f32 {
+    PI - (PI - pitch).rem_euclid(2.0 * PI)
+}
+
+/// Marks the level button active while the bound camera is upside down, and the arrow buttons
+/// disabled when there is no view to step to.
+pub(crate) fn update_buttons(
+    camera: Query<(&PanOrbitCamera, Option<&ViewcubeHistory>), With<crate::ViewcubeBinding>>,
+    mut buttons: Query<(&ViewcubeButton, &mut ViewcubePartState)>,
+) {
+    let camera = camera.get_single().ok();
+    let upside_down =
+        camera.is_some_and(|(camera, _)| wrap_pitch(camera.target_pitch).abs() > PI_2);
+    let history = camera.and_then(|(_, history)| history);
+    for (button, mut state) in buttons.iter_mut() {
+        let (is_active, is_disabled) = match button {
+            ViewcubeButton::Level => (upside_down, false),
+            ViewcubeButton::Back => (false, !history.is_some_and(|h| h.can_go_back())),
+            ViewcubeButton::Forward => (false, !history.is_some_and(|h| h.can_go_forward())),
+        };
+        if state.active != is_active {
+            state.active = is_active;
+        }
+        if state.disabled != is_disabled {
+            state.disabled = is_disabled;
+        }
+    }
+}
+
+/// Levels the bound camera for each [`ViewcubeLevel`].
+#[allow(clippy::type_complexity)]
+pub(crate) fn level_view(
+    mut levels: EventReader<ViewcubeLevel>,
+    mut camera: Query<
+        (
+            &mut PanOrbitCamera,
+            Option<&mut ViewcubeHistory>,
+            Option<&Projection>,
+        ),
+        With<crate::ViewcubeBinding>,
+    >,
+) {
+    for _ in levels.read() {
+        let Ok((mut camera, history, projection)) = camera.get_single_mut() else {
+            continue;
+        };
+        let pitch = wrap_pitch(camera.target_pitch);
+        if pitch.abs() <= PI_2 {
+            continue;
+        }
+        if let Some(mut history) = history {
+            history.push(ViewcubeViewState::of(&camera, projection));
+        }
+        // Same direction with the up vector flipped: turn around and mirror the pitch. Offsets
+        // from the current targets keep the transition short
+        let level = pitch.signum() * PI - pitch;
+        camera.target_pitch += level - pitch;
+        camera.target_yaw += PI;
+    }
+}
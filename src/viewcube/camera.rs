@@ -0,0 +1,244 @@
+use std::f32::consts::TAU;
+
+use bevy::{
+    ecs::{
+        query::With,
+        system::{Query, SystemParam},
+    },
+    hierarchy::Parent,
+    math::{Quat, Vec3},
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::ViewcubeSettings;
+use crate::PI_2;
+
+pub(crate) type BoundCameraFilter = (With<PanOrbitCamera>, With<crate::ViewcubeBinding>);
+
+/// The `PanOrbitCamera` marked with [`ViewcubeBinding`](crate::ViewcubeBinding).
+#[derive(SystemParam)]
+pub(crate) struct BoundCamera<'w, 's> {
+    cameras: Query<'w, 's, (&'static Transform, Option<&'static Parent>), BoundCameraFilter>,
+    globals: Query<'w, 's, &'static GlobalTransform>,
+}
+
+impl BoundCamera<'_, '_> {
+    /// Rotation of the frame the camera orbits in.
+    ///
+    /// `PanOrbitCamera` writes its yaw and pitch into the local `Transform` and has no base
+    /// transform of its own, so a camera parented to a rotated entity orbits in that entity's
+    /// frame.
+    pub(crate) fn base_rotation(&self) -> Option<Quat> {
+        let (_, parent) = self.cameras.get_single().ok()?;
+        Some(
+            parent
+                .and_then(|parent| self.globals.get(parent.get()).ok())
+                .map_or(Quat::IDENTITY, |global| global.rotation()),
+        )
+    }
+
+    /// Rotation from the Y-up cube to the world, see [`ViewcubeSettings::up_axis`] and
+    /// [`ViewcubeSettings::reference`].
+    pub(crate) fn cube_rotation(&self, settings: &ViewcubeSettings) -> Quat {
+        let reference = settings
+            .reference
+            .and_then(|entity| self.globals.get(entity).ok());
+        match reference {
+            // The conventions apply in the local frame of the reference entity, whose own Y-up
+            // frame stands in for the camera base
+            Some(global) => global.rotation() * settings.up_axis.rotation(Quat::IDENTITY),
+            None => settings
+                .up_axis
+                .rotation(self.base_rotation().unwrap_or(Quat::IDENTITY)),
+        }
+    }
+
+    /// Yaw and pitch looking from `direction`, given in the frame of the cube, `None` if it has no
+    /// direction.
+    pub(crate) fn orbit_angles(
+        &self,
+        settings: &ViewcubeSettings,
+        direction: Vec3,
+    ) -> Option<(f32, f32)> {
+        // Look from the direction in the world, expressed in the frame the camera orbits in
+        let base = self.base_rotation().unwrap_or(Quat::IDENTITY);
+        orbit_angles(base.inverse() * self.cube_rotation(settings) * direction)
+    }
+
+    /// Direction of the camera at `yaw` and `pitch`, in the frame of the cube.
+    pub(crate) fn cube_direction(&self, settings: &ViewcubeSettings, yaw: f32, pitch: f32) -> Vec3 {
+        let base = self.base_rotation().unwrap_or(Quat::IDENTITY);
+        self.cube_rotation(settings).inverse() * base * orbit_direction(yaw, pitch)
+    }
+
+    /// World rotation of the camera, up to date with this frame's orbit.
+    pub(crate) fn rotation(&self) -> Option<Quat> {
+        let (transform, _) = self.cameras.get_single().ok()?;
+        Some(self.base_rotation()? * transform.rotation)
+    }
+}
+
+/// Unit vector from the focus towards a `PanOrbitCamera` at `yaw` and `pitch`, in its base frame.
+pub(crate) fn orbit_direction(yaw: f32, pitch: f32) -> Vec3 {
+    Vec3::new(
+        yaw.sin() * pitch.cos(),
+        pitch.sin(),
+        yaw.cos() * pitch.cos(),
+    )
+}
+
+/// Yaw and pitch of a `PanOrbitCamera` looking from `direction`, given in its base frame, `None`
+/// if `direction` is zero or not finite.
+pub(crate) fn orbit_angles(direction: Vec3) -> Option<(f32, f32)> {
+    let direction = direction.try_normalize()?;
+    let pitch = direction.y.clamp(-1.0, 1.0).asin();
+    // Straight above or below, keep the default heading
+    let yaw = if direction.x.abs() < 1e-5 && direction.z.abs() < 1e-5 {
+        0.0
+    } else {
+        direction.x.atan2(direction.z)
+    };
+    Some((yaw, pitch))
+}
+
+/// Tolerance on the limits, so views exactly on a limit such as `Top` with a `PI / 2` pitch limit
+/// stay reachable.
+const LIMIT_EPSILON: f32 = 1e-4;
+
+/// `yaw` shifted by whole turns to be as close as possible to `current`, so the camera takes the
+/// short way round.
+pub(crate) fn nearest_yaw(yaw: f32, current: f32) -> f32 {
+    yaw + ((current - yaw) / TAU).round() * TAU
+}
+
+/// `yaw` and `pitch` brought within the limits of `camera`, and whether they were already
+/// reachable.
+///
+/// The yaw is free when looking straight up or down, and is shifted by whole turns to stay close
+/// to the current yaw of `camera`, or to fit its limits.
+pub(crate) fn limit_angles(camera: &PanOrbitCamera, yaw: f32, pitch: f32) -> (f32, f32, bool) {
+    let within = |value: f32, lower: Option<f32>, upper: Option<f32>| {
+        lower.is_none_or(|lower| value >= lower - LIMIT_EPSILON)
+            && upper.is_none_or(|upper| value <= upper + LIMIT_EPSILON)
+    };
+    let clamp = |value: f32, lower: Option<f32>, upper: Option<f32>| {
+        value.clamp(lower.unwrap_or(f32::MIN), upper.unwrap_or(f32::MAX))
+    };
+    let (yaw_lower, yaw_upper) = (camera.yaw_lower_limit, camera.yaw_upper_limit);
+    let (pitch_lower, pitch_upper) = (camera.pitch_lower_limit, camera.pitch_upper_limit);
+
+    let pitch_reachable = within(pitch, pitch_lower, pitch_upper);
+    let vertical = (pitch.abs() - PI_2).abs() < LIMIT_EPSILON;
+    let nearest = nearest_yaw(yaw, camera.target_yaw);
+    let turned = [nearest, nearest + TAU, nearest - TAU]
+        .into_iter()
+        .find(|&yaw| within(yaw, yaw_lower, yaw_upper));
+    let yaw_reachable = vertical || turned.is_some();
+    (
+        clamp(turned.unwrap_or(nearest), yaw_lower, yaw_upper),
+        clamp(pitch, pitch_lower, pitch_upper),
+        yaw_reachable && pitch_reachable,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::*;
+
+    #[test]
+    fn orbit_angles_round_trip() {
+        for yaw_step in -7..=8 {
+            for pitch_step in -3..=3 {
+                let yaw = yaw_step as f32 * PI_2 / 2.0 - 0.1;
+                let pitch = pitch_step as f32 * PI_2 / 4.0;
+                let (actual_yaw, actual_pitch) = orbit_angles(orbit_direction(yaw, pitch)).unwrap();
+                assert!((actual_pitch - pitch).abs() < 1e-5, "pitch {pitch}");
+                // Yaw comes back in (-PI, PI]
+                let turns = (actual_yaw - yaw) / TAU;
+                assert!((turns - turns.round()).abs() < 1e-5, "yaw {yaw}");
+            }
+        }
+    }
+
+    #[test]
+    fn orbit_angles_of_vertical_directions_keep_the_default_heading() {
+        assert_eq!(orbit_angles(Vec3::Y), Some((0.0, PI_2)));
+        assert_eq!(orbit_angles(Vec3::NEG_Y), Some((0.0, -PI_2)));
+    }
+
+    #[test]
+    fn orbit_angles_reject_degenerate_directions() {
+        assert_eq!(orbit_angles(Vec3::ZERO), None);
+        assert_eq!(orbit_angles(Vec3::NAN), None);
+        assert_eq!(orbit_angles(Vec3::new(f32::INFINITY, 0.0, 1.0)), None);
+    }
+
+    fn limited_camera(yaw: (f32, f32), pitch: (f32, f32)) -> PanOrbitCamera {
+        PanOrbitCamera {
+            yaw_lower_limit: Some(yaw.0),
+            yaw_upper_limit: Some(yaw.1),
+            pitch_lower_limit: Some(pitch.0),
+            pitch_upper_limit: Some(pitch.1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn limit_angles_turn_the_yaw_into_limits_beyond_half_a_turn() {
+        let camera = limited_camera((PI_2, 3.0 * PI_2), (-PI_2, PI_2));
+        let (yaw, pitch, reachable) = limit_angles(&camera, -PI_2 - 0.1, 0.0);
+        assert!(reachable);
+        assert!((yaw - (3.0 * PI_2 - 0.1)).abs() < 1e-5);
+        assert_eq!(pitch, 0.0);
+    }
+
+    #[test]
+    fn limit_angles_clamp_unreachable_views() {
+        let camera = limited_camera((-0.5, 0.5), (-0.5, 0.5));
+        assert_eq!(limit_angles(&camera, 1.0, 1.0), (0.5, 0.5, false));
+        assert_eq!(limit_angles(&camera, 0.2, -1.0), (0.2, -0.5, false));
+    }
+
+    #[test]
+    fn limit_angles_reach_vertical_views_whatever_the_yaw_limits() {
+        let camera = limited_camera((0.5, 1.0), (-PI_2, PI_2));
+        assert!(limit_angles(&camera, 0.0, PI_2).2);
+        assert!(limit_angles(&camera, 0.0, -PI_2).2);
+        assert!(!limit_angles(&camera, 0.0, PI_2 - 0.1).2);
+    }
+
+    #[test]
+    fn limit_angles_tolerate_rounding_at_the_limits() {
+        let camera = limited_camera((-PI_2, PI_2), (0.0, 0.5));
+        assert!(limit_angles(&camera, PI_2, 0.5).2);
+        assert!(limit_angles(&camera, PI_2 + LIMIT_EPSILON / 2.0, -LIMIT_EPSILON / 2.0).2);
+        assert!(!limit_angles(&camera, PI_2 + LIMIT_EPSILON * 2.0, 0.0).2);
+        assert!(!limit_angles(&camera, 0.0, 0.5 + LIMIT_EPSILON * 2.0).2);
+    }
+
+    #[test]
+    fn nearest_yaw_takes_the_short_way_round() {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+        assert!(close(nearest_yaw(0.5, 0.0), 0.5));
+        assert!(close(nearest_yaw(-PI_2, 3.0 * TAU), 3.0 * TAU - PI_2));
+        assert!(close(nearest_yaw(PI - 0.1, -PI + 0.1), -PI - 0.1));
+        assert!(close(
+            nearest_yaw(-PI + 0.1, -2.0 * TAU),
+            -2.0 * TAU - PI + 0.1
+        ));
+    }
+
+    #[test]
+    fn limit_angles_stay_close_to_the_current_yaw() {
+        let camera = PanOrbitCamera {
+            target_yaw: 2.0 * TAU + 3.0,
+            ..Default::default()
+        };
+        let (yaw, _, reachable) = limit_angles(&camera, -3.0, 0.0);
+        assert!(reachable);
+        assert!((yaw - (3.0 * TAU - 3.0)).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::VecDeque;
+
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        event::{Event, EventReader},
+        query::{With, Without},
+        system::{Commands, Query},
+    },
+    math::Vec3,
+    render::camera::Projection,
+};
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::camera::BoundCameraFilter;
+
+/// Where a `PanOrbitCamera` was looking from, as recorded by [`ViewcubeHistory`].
+#[derive(Clone, Debug)]
+pub struct ViewcubeViewState {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub radius: f32,
+    pub focus: Vec3,
+    /// Projection of the camera, `None` if it has no `Projection` component.
+    pub projection: Option<Projection>,
+}
+
+impl ViewcubeViewState {
+    /// The view `camera` is heading to.
+    pub fn of(camera: &PanOrbitCamera, projection: Option<&Projection>) -> Self {
+        Self {
+            yaw: camera.target_yaw,
+            pitch: camera.target_pitch,
+            radius: camera.target_radius,
+            focus: camera.target_focus,
+            projection: projection.cloned(),
+        }
+    }
+
+    /// Moves `camera` back to this view.
+    pub fn apply(&self, camera: &mut PanOrbitCamera, projection: Option<&mut Projection>) {
+        camera.target_yaw = self.yaw;
+        camera.target_pitch = self.pitch;
+        camera.target_radius = self.radius;
+        camera.target_focus = self.focus;
+        if let (Some(projection), Some(recorded)) = (projection, &self.projection) {
+            *projection = recorded.clone();
+        }
+    }
+}
+
+/// Views a camera had before each snap made through the viewcube, to step back and forth.
+///
+/// Added with the default depth to the bound camera if it has none, insert it yourself to change
+/// the depth.
+#[derive(Component, Clone, Debug)]
+pub struct ViewcubeHistory {
+    /// Largest number of views kept to step back to, the oldest are dropped first.
+    pub depth: usize,
+    back: VecDeque<ViewcubeViewState>,
+    forward: Vec<ViewcubeViewState>,
+}
+
+impl Default for ViewcubeHistory {
+    fn default() -> Self {
+        Self::new(20)
+    }
+}
+
+impl ViewcubeHistory {
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            back: VecDeque::new(),
+            forward: Vec::new(),
+        }
+    }
+
+    /// Records the view the camera leaves, dropping the views stepped back from.
+    pub fn push(&mut self, view: ViewcubeViewState) {
+        self.forward.clear();
+        self.back.push_back(view);
+        while self.back.len() > self.depth {
+            self.back.pop_front();
+        }
+    }
+
+    /// The view before `current`, which becomes the next one forward.
+    pub fn back(&mut self, current: ViewcubeViewState) -> Option<ViewcubeViewState> {
+        let view = self.back.pop_back()?;
+        self.forward.push(current);
+        Some(view)
+    }
+
+    /// The view after `current`, which becomes the previous one.
+    pub fn forward(&mut self, current: ViewcubeViewState) -> Option<ViewcubeViewState> {
+        let view = self.forward.pop()?;
+        self.back.push_back(current);
+        Some(view)
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.back.clear();
+        self.forward.clear();
+    }
+}
+
+/// Steps the [`ViewcubeHistory`] of the bound camera, sent by the arrow buttons.
+#[derive(Event, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ViewcubeHistoryStep {
+    Back,
+    Forward,
+}
+
+pub(crate) fn init_history(
+    mut commands: Commands,
+    cameras: Query<Entity, (BoundCameraFilter, Without<ViewcubeHistory>)>,
+) {
+    for camera in cameras.iter() {
+        commands.entity(camera).insert(ViewcubeHistory::default());
+    }
+}
+
+pub(crate) fn step_history(
+    mut steps: EventReader<ViewcubeHistoryStep>,
+    mut camera: Query<
+        (
+            &mut PanOrbitCamera,
+            &mut ViewcubeHistory,
+            Option<&mut Projection>,
+        ),
+        With<crate::ViewcubeBinding>,
+    >,
+) {
+    for step in steps.read() {
+        let Ok((mut camera, mut history, mut projection)) = camera.get_single_mut() else {
+            continue;
+        };
+        let current = ViewcubeViewState::of(&camera, projection.as_deref());
+        let view = match step {
+            ViewcubeHistoryStep::Back => history.back(current),
+            ViewcubeHistoryStep::Forward => history.forward(current),
+        };
+        if let Some(view) = view {
+            view.apply(&mut camera, projection.as_deref_mut());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(yaw: f32) -> ViewcubeViewState {
+        ViewcubeViewState {
+            yaw,
+            pitch: 0.0,
+            radius: 1.0,
+            focus: Vec3::ZERO,
+            projection: None,
+        }
+    }
+
+    #[test]
+    fn back_and_forward_walk_the_pushed_views() {
+        let mut history = ViewcubeHistory::default();
+        assert!(!history.can_go_back());
+        history.push(view(1.0));
+        history.push(view(2.0));
+
+        assert_eq!(history.back(view(3.0)).map(|view| view.yaw), Some(2.0));
+        assert_eq!(history.back(view(2.0)).map(|view| view.yaw), Some(1.0));
+        assert!(history.back(view(1.0)).is_none());
+        assert_eq!(history.forward(view(1.0)).map(|view| view.yaw), Some(2.0));
+        assert_eq!(history.forward(view(2.0)).map(|view| view.yaw), Some(3.0));
+        assert!(!history.can_go_forward());
+        assert!(history.can_go_back());
+    }
+
+    #[test]
+    fn push_drops_the_views_stepped_back_from() {
+        let mut history = ViewcubeHistory::default();
+        history.push(view(1.0));
+        history.back(view(2.0));
+        assert!(history.can_go_forward());
+        history.push(view(1.0));
+        assert!(!history.can_go_forward());
+        assert!(history.forward(view(3.0)).is_none());
+    }
+
+    #[test]
+    fn push_keeps_at_most_depth_views() {
+        let mut history = ViewcubeHistory::new(2);
+        for yaw in 0..5 {
+            history.push(view(yaw as f32));
+        }
+        assert_eq!(history.back(view(5.0)).map(|view| view.yaw), Some(4.0));
+        assert_eq!(history.back(view(4.0)).map(|view| view.yaw), Some(3.0));
+        assert!(history.back(view(3.0)).is_none());
+    }
+
+    #[test]
+    fn clear_forgets_both_ways() {
+        let mut history = ViewcubeHistory::default();
+        history.push(view(1.0));
+        history.push(view(2.0));
+        history.back(view(3.0));
+        history.clear();
+        assert!(!history.can_go_back());
+        assert!(!history.can_go_forward());
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::{
+    ecs::{
+        event::EventWriter,
+        query::With,
+        system::{Local, Query, Res},
+    },
+    input::{keyboard::KeyCode, mouse::MouseButton, ButtonInput},
+    render::camera::Camera,
+    window::Window,
+};
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::{camera::BoundCamera, CubePart, ViewcubeSettings, ViewcubeSnap};
+
+/// Snaps the bound camera to the nearest enabled part when a free orbit ends, see
+/// [`ViewcubeSettings::magnet`].
+///
+/// Orbits started over the viewcube are its own clicks and left alone.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn magnetic_snap(
+    settings: Res<ViewcubeSettings>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    small_view: Query<&Camera, With<crate::SmallView>>,
+    bound_camera: BoundCamera,
+    camera: Query<&PanOrbitCamera, With<crate::ViewcubeBinding>>,
+    mut snaps: EventWriter<ViewcubeSnap>,
+    // Yaw and pitch when the orbit button was pressed
+    mut start: Local<Option<(f32, f32)>>,
+) {
+    let (Some(magnet), Ok(camera)) = (settings.magnet, camera.get_single()) else {
+        *start = None;
+        return;
+    };
+    if mouse.just_pressed(camera.button_orbit) {
+        let modifier = camera.modifier_orbit.is_none_or(|key| keys.pressed(key));
+        *start = (camera.enabled && modifier && !super::cursor_over_view(&windows, &small_view))
+            .then_some((camera.target_yaw, camera.target_pitch));
+    }
+    if !mouse.just_released(camera.button_orbit) {
+        return;
+    }
+    let Some((yaw, pitch)) = start.take() else {
+        return;
+    };
+
+    let from = bound_camera.cube_direction(&settings, yaw, pitch);
+    let to = bound_camera.cube_direction(&settings, camera.target_yaw, camera.target_pitch);
+    if from.angle_between(to) < magnet.min_drag {
+        return;
+    }
+    let nearest = CubePart::ALL
+        .into_iter()
+        .filter(|part| settings.is_enabled(*part))
+        .map(|part| (part, part.direction(&settings).angle_between(to)))
+        .filter(|(_, angle)| *angle <= magnet.threshold)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+    if let Some((part, _)) = nearest {
+        snaps.send(ViewcubeSnap::Part(part));
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::{
+    prelude::*,
+    render::{
+        mesh::Indices, render_asset::RenderAssetUsages, render_resource::PrimitiveTopology,
+        view::RenderLayers,
+    },
+};
+
+use super::{ViewcubeCenter, ViewcubeKind, ViewcubeMesh, ViewcubeStyle, CUBE_SIZE};
+
+/// Marks the outline drawn along the 12 cube edges.
+#[derive(Component)]
+pub(crate) struct ViewcubeOutlineMesh;
+
+/// Thin boxes of `width` along the 12 edges of a cube of `size`, centered on the origin.
+///
+/// Edges bevelled by `bevel` get one box along each border of the bevel instead, where it meets
+/// the faces.
+pub(crate) fn outline_mesh(size: f32, bevel: f32, width: f32) -> Mesh {
+    let half = size / 2.0;
+    let inner = half - bevel;
+    let borders: &[(f32, f32)] = if bevel > 0.0 {
+        &[(half, inner), (inner, half)]
+    } else {
+        &[(half, half)]
+    };
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, Vec::<[f32; 3]>::new())
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, Vec::<[f32; 3]>::new())
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, Vec::<[f32; 2]>::new())
+    .with_inserted_indices(Indices::U32(vec![]));
+    for axis in 0..3 {
+        for (sign_a, sign_b) in [(-1.0, -1.0), (-1.0, 1.0), (1.0, -1.0), (1.0, 1.0)] {
+            for (a, b) in borders {
+                let mut extent = Vec3::splat(width);
+                extent[axis] = 2.0 * inner + width;
+                let mut center = Vec3::ZERO;
+                center[(axis + 1) % 3] = sign_a * a;
+                center[(axis + 2) % 3] = sign_b * b;
+                let edge = Mesh::from(Cuboid::from_size(extent))
+                    .transformed_by(Transform::from_translation(center));
+                mesh.merge(&edge);
+            }
+        }
+    }
+    mesh
+}
+
+/// Rebuilds the edge outline whenever the width of [`ViewcubeStyle::outline`] changes or it is
+/// turned on or off, its colour follows in `update_materials`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_outline(
+    mut commands: Commands,
+    style: Res<ViewcubeStyle>,
+    kind: Res<ViewcubeKind>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    center: Query<Entity, With<ViewcubeCenter>>,
+    outlines: Query<Entity, With<ViewcubeOutlineMesh>>,
+    mut drawn: Local<Option<f32>>,
+) {
+    if !style.is_changed() {
+        return;
+    }
+    let width = style.outline.map(|outline| outline.width);
+    if width == *drawn {
+        return;
+    }
+    for outline in outlines.iter() {
+        commands.entity(outline).despawn_recursive();
+    }
+    *drawn = None;
+    let (Some(outline), Some(width), Ok(center)) = (style.outline, width, center.get_single())
+    else {
+        return;
+    };
+    *drawn = Some(width);
+    commands.entity(center).with_children(|builder| {
+        builder.spawn((
+            Mesh3d(meshes.add(outline_mesh(CUBE_SIZE, kind.bevel(), width))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: outline.color,
+                unlit: true,
+                ..default()
+            })),
+            Transform::default(),
+            RenderLayers::layer(13),
+            ViewcubeMesh,
+            ViewcubeOutlineMesh,
+        ));
+    });
+}
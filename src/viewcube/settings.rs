@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+use bevy::{
+    ecs::{entity::Entity, system::Resource},
+    input::keyboard::KeyCode,
+    math::{Mat3, Quat, Vec3},
+};
+
+use super::CubePart;
+use crate::{PI_2, PI_4};
+
+/// Behaviour of the viewcube.
+///
+/// Inserted with its default value by [`BevyViewCubePlugin`](super::BevyViewCubePlugin) if not
+/// already present, and can be changed at runtime.
+#[derive(Resource, Clone, Debug)]
+pub struct ViewcubeSettings {
+    /// Where the `Top` face points to.
+    pub up_axis: ViewcubeUpAxis,
+    /// Entity whose `GlobalTransform` rotation the cube is aligned with, `None` for the world.
+    ///
+    /// The cube then shows the orientation of the camera relative to that entity, `up_axis` is
+    /// applied in its local frame, and clicking a part snaps to the view aligned with it.
+    pub reference: Option<Entity>,
+    /// Angles of the views from the corners.
+    pub corner_view: ViewcubeCornerView,
+    /// Angle of the views from the edges.
+    pub edge_view: ViewcubeEdgeView,
+    /// Parts drawn disabled and ignored by picking.
+    pub disabled_parts: HashSet<CubePart>,
+    /// Snap to the nearest part after orbiting freely, `None` to leave the camera where it is.
+    pub magnet: Option<ViewcubeMagnet>,
+    /// Key held while clicking an arrow of the trident inside the cube to look from the negative
+    /// side of its axis instead of the positive one.
+    pub opposite_key: Option<KeyCode>,
+}
+
+impl Default for ViewcubeSettings {
+    fn default() -> Self {
+        Self {
+            up_axis: ViewcubeUpAxis::default(),
+            reference: None,
+            corner_view: ViewcubeCornerView::default(),
+            edge_view: ViewcubeEdgeView::default(),
+            disabled_parts: HashSet::new(),
+            magnet: None,
+            opposite_key: Some(KeyCode::ShiftLeft),
+        }
+    }
+}
+
+/// Snapping to the nearest part when the orbit button of the bound camera is released, see
+/// [`ViewcubeSettings::magnet`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewcubeMagnet {
+    /// Largest angle in radians between the camera and a part direction that snaps to the part.
+    pub threshold: f32,
+    /// Smallest orbit in radians since the button was pressed that counts as a drag, so clicks
+    /// and accidental nudges leave the camera alone.
+    pub min_drag: f32,
+}
+
+impl Default for ViewcubeMagnet {
+    fn default() -> Self {
+        Self {
+            threshold: 10f32.to_radians(),
+            min_drag: 2f32.to_radians(),
+        }
+    }
+}
+
+impl ViewcubeSettings {
+    /// Aligns the cube with `entity`, see [`ViewcubeSettings::reference`].
+    pub fn align_to(&mut self, entity: Entity) {
+        self.reference = Some(entity);
+    }
+
+    /// Aligns the cube with the world again.
+    pub fn align_to_world(&mut self) {
+        self.reference = None;
+    }
+
+    pub fn is_enabled(&self, part: CubePart) -> bool {
+        !self.disabled_parts.contains(&part)
+    }
+
+    /// Turns `part` on or off, see [`ViewcubeSettings::disabled_parts`].
+    pub fn set_enabled(&mut self, part: CubePart, enabled: bool) {
+        if enabled {
+            self.disabled_parts.remove(&part);
+        } else {
+            self.disabled_parts.insert(part);
+        }
+    }
+
+    /// Turns off every edge and corner, leaving the 6 faces.
+    pub fn faces_only(&mut self) {
+        self.disabled_parts
+            .extend(CubePart::ALL.into_iter().filter(|part| !part.is_face()));
+    }
+}
+
+/// Convention for the views from the 8 corners.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ViewcubeCornerView {
+    /// 45° around the vertical axis and 45° above the horizon.
+    #[default]
+    Degrees45,
+    /// True isometric: 45° around and atan(1/√2), about 35.26°, above the horizon, so the three
+    /// axes are equally foreshortened.
+    Isometric,
+    /// 45° around and 30° above the horizon, the 2:1 dimetric of technical illustration.
+    Dimetric,
+    /// `yaw` around the vertical axis from the front or back face towards the side, and `pitch`
+    /// above the horizon, both in radians and mirrored for each corner.
+    Custom { yaw: f32, pitch: f32 },
+}
+
+impl ViewcubeCornerView {
+    /// Yaw away from the front or back face and pitch away from the horizon, in radians.
+    pub fn angles(self) -> (f32, f32) {
+        match self {
+            ViewcubeCornerView::Degrees45 => (PI_4, PI_4),
+            ViewcubeCornerView::Isometric => (PI_4, (1.0 / 2f32.sqrt()).atan()),
+            ViewcubeCornerView::Dimetric => (PI_4, 30f32.to_radians()),
+            ViewcubeCornerView::Custom { yaw, pitch } => (yaw, pitch),
+        }
+    }
+}
+
+/// Convention for the views from the 12 edges.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ViewcubeEdgeView {
+    /// Halfway between the two faces.
+    #[default]
+    Degrees45,
+    /// Angle in radians away from the front, back, left or right face: above the horizon for the
+    /// top and bottom edges, towards the side for the vertical edges.
+    Custom(f32),
+}
+
+impl ViewcubeEdgeView {
+    /// Angle away from the first face, in radians.
+    pub fn angle(self) -> f32 {
+        match self {
+            ViewcubeEdgeView::Degrees45 => PI_4,
+            ViewcubeEdgeView::Custom(angle) => angle,
+        }
+    }
+}
+
+/// Coordinate convention the viewcube describes.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ViewcubeUpAxis {
+    /// The frame the bound camera orbits in: `Top` is straight above the orbit and `Front` is
+    /// where a yaw and pitch of zero look from.
+    ///
+    /// `PanOrbitCamera` orbits in the frame of its parent, this is +Y up in the world when the
+    /// camera has no rotated parent.
+    #[default]
+    CameraBase,
+    /// Bevy's convention: `Top` is +Y and `Front` is +Z.
+    Y,
+    /// CAD and GIS convention: `Top` is +Z and `Front` is -Y.
+    Z,
+    /// `Top` along `up`, and `Front` along `front` made perpendicular to `up`.
+    Custom { up: Vec3, front: Vec3 },
+}
+
+impl ViewcubeUpAxis {
+    /// Rotation from the Y-up cube to the world, given the rotation of the frame the bound camera
+    /// orbits in.
+    pub fn rotation(self, camera_base: Quat) -> Quat {
+        match self {
+            ViewcubeUpAxis::CameraBase => camera_base,
+            ViewcubeUpAxis::Y => Quat::IDENTITY,
+            ViewcubeUpAxis::Z => Quat::from_rotation_x(PI_2),
+            ViewcubeUpAxis::Custom { up, front } => {
+                let up = up.try_normalize().unwrap_or(Vec3::Y);
+                let front = front
+                    .reject_from_normalized(up)
+                    .try_normalize()
+                    .unwrap_or_else(|| up.any_orthonormal_vector());
+                Quat::from_mat3(&Mat3::from_cols(up.cross(front), up, front))
+            }
+        }
+    }
+}
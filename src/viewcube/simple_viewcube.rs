@@ -4,13 +4,16 @@ use bevy::render::view::RenderLayers;
 
 use crate::generate_viewcube_face;
 
-use super::{CubePart, ViewcubeHit};
+use super::{
+    on_part_click, on_part_out, on_part_over, CubePart, ViewcubePart, ViewcubeTextures, CUBE_SIZE,
+};
 
 pub fn setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     asset_server: Res<AssetServer>,
+    textures: Res<ViewcubeTextures>,
 ) {
     let center = Vec3::new(0.6, 0.6, 0.6);
 
@@ -22,386 +25,90 @@ pub fn setup(
             GlobalTransform::default(),
             RenderLayers::layer(13),
             super::ViewcubeCenter,
+            super::ViewcubeMesh,
         ))
         .with_children(|builder| {
-            builder.spawn((
-                Mesh3d(meshes.add(crate::prelude::BevyTridentAxis::default())),
-                MeshMaterial3d(materials.add(StandardMaterial::default())),
-                Transform::from_translation(-center),
-                GlobalTransform::default(),
-                RenderLayers::layer(13),
-            ));
+            super::axes::spawn_trident(builder, &mut meshes, &mut materials, -center);
             generate_viewcube_simple_face(
-                0.8f32,
+                CUBE_SIZE,
+                CUBE_SIZE / 2.0,
                 builder,
                 &mut meshes,
                 &mut materials,
                 &asset_server,
+                &textures,
             );
         });
 }
 
+/// Spawns the 6 faces as squares of `size` at `half` from the cube center.
 pub fn generate_viewcube_simple_face(
     size: f32,
+    half: f32,
     commands: &mut ChildBuilder,
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<StandardMaterial>,
     asset_server: &AssetServer,
+    textures: &ViewcubeTextures,
 ) {
     let plane = Plane3d::default().mesh().size(size, size);
-    let half = size / 2.0;
 
-    // Right (+X)
-    commands
-        .spawn(generate_viewcube_face!(
-            meshes,
-            materials,
-            plane,
-            StandardMaterial {
-                base_color_texture: Some(asset_server.load("cube/right.png")),
-                ..default()
-            },
-            Transform::from_matrix(Mat4::from_rotation_translation(
-                Quat::from_rotation_z(-crate::PI / 2.0) * Quat::from_rotation_y(crate::PI / 2.0),
-                Vec3::new(half, 0.0, 0.0),
-            )),
-            ViewcubeHit(CubePart::Right)
-        ))
-        .observe(
-            move |trigger: Trigger<Pointer<Click>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(ViewcubeHit(CubePart::Right))
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/right.png")),
-                        base_color: Color::srgb(0.6, 0.6, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Over>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/right.png")),
-                        base_color: Color::srgb(0.8, 0.8, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Out>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/right.png")),
-                        ..default()
-                    })));
-            },
-        );
-
-    // Left (-X)
-    commands
-        .spawn(generate_viewcube_face!(
-            meshes,
-            materials,
-            plane,
-            StandardMaterial {
-                base_color_texture: Some(asset_server.load("cube/left.png")),
-                ..default()
-            },
-            Transform::from_matrix(Mat4::from_rotation_translation(
-                Quat::from_rotation_z(crate::PI / 2.0) * Quat::from_rotation_y(-crate::PI / 2.0),
-                Vec3::new(-half, 0.0, 0.0),
-            )),
-            ViewcubeHit(CubePart::Left)
-        ))
-        .observe(
-            move |trigger: Trigger<Pointer<Click>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(ViewcubeHit(CubePart::Left))
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/left.png")),
-                        base_color: Color::srgb(0.6, 0.6, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Over>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/left.png")),
-                        base_color: Color::srgb(0.8, 0.8, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Out>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/left.png")),
-                        ..default()
-                    })));
-            },
-        );
-
-    // Top (+Y)
-    commands
-        .spawn(generate_viewcube_face!(
-            meshes,
-            materials,
-            plane,
-            StandardMaterial {
-                base_color_texture: Some(asset_server.load("cube/top.png")),
-                ..default()
-            },
-            Transform::from_matrix(Mat4::from_rotation_translation(
-                Quat::from_rotation_x(0.0),
-                Vec3::new(0.0, half, 0.0),
-            )),
-            ViewcubeHit(CubePart::Top)
-        ))
-        .observe(
-            move |trigger: Trigger<Pointer<Click>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(ViewcubeHit(CubePart::Top))
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/top.png")),
-                        base_color: Color::srgb(0.6, 0.6, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Over>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/top.png")),
-                        base_color: Color::srgb(0.8, 0.8, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Out>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/top.png")),
-                        ..default()
-                    })));
-            },
-        );
+    let faces = [
+        // Right (+X)
+        (
+            CubePart::Right,
+            Quat::from_rotation_z(-crate::PI / 2.0) * Quat::from_rotation_y(crate::PI / 2.0),
+            Vec3::new(half, 0.0, 0.0),
+        ),
+        // Left (-X)
+        (
+            CubePart::Left,
+            Quat::from_rotation_z(crate::PI / 2.0) * Quat::from_rotation_y(-crate::PI / 2.0),
+            Vec3::new(-half, 0.0, 0.0),
+        ),
+        // Top (+Y)
+        (
+            CubePart::Top,
+            Quat::from_rotation_x(0.0),
+            Vec3::new(0.0, half, 0.0),
+        ),
+        // Bottom (-Y)
+        (
+            CubePart::Bottom,
+            Quat::from_rotation_x(crate::PI),
+            Vec3::new(0.0, -half, 0.0),
+        ),
+        // Front (+Z)
+        (
+            CubePart::Front,
+            Quat::from_rotation_x(crate::PI / 2.0),
+            Vec3::new(0.0, 0.0, half),
+        ),
+        // Back (-Z)
+        (
+            CubePart::Back,
+            Quat::from_rotation_x(-crate::PI / 2.0) * Quat::from_rotation_y(crate::PI),
+            Vec3::new(0.0, 0.0, -half),
+        ),
+    ];
 
-    // Bottom (-Y)
-    commands
-        .spawn(generate_viewcube_face!(
-            meshes,
-            materials,
-            plane,
-            StandardMaterial {
-                base_color_texture: Some(asset_server.load("cube/bottom.png")),
-                ..default()
-            },
-            Transform::from_matrix(Mat4::from_rotation_translation(
-                Quat::from_rotation_x(crate::PI),
-                Vec3::new(0.0, -half, 0.0),
-            )),
-            ViewcubeHit(CubePart::Bottom)
-        ))
-        .observe(
-            move |trigger: Trigger<Pointer<Click>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(ViewcubeHit(CubePart::Bottom))
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/bottom.png")),
-                        base_color: Color::srgb(0.6, 0.6, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Over>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/bottom.png")),
-                        base_color: Color::srgb(0.8, 0.8, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Out>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/bottom.png")),
-                        ..default()
-                    })));
-            },
-        );
-
-    // Front (+Z)
-    commands
-        .spawn(generate_viewcube_face!(
-            meshes,
-            materials,
-            plane,
-            StandardMaterial {
-                base_color_texture: Some(asset_server.load("cube/front.png")),
-                ..default()
-            },
-            Transform::from_matrix(Mat4::from_rotation_translation(
-                Quat::from_rotation_x(crate::PI / 2.0),
-                Vec3::new(0.0, 0.0, half),
-            )),
-            ViewcubeHit(CubePart::Front)
-        ))
-        .observe(
-            move |trigger: Trigger<Pointer<Click>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(ViewcubeHit(CubePart::Front))
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/front.png")),
-                        base_color: Color::srgb(0.6, 0.6, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Over>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/front.png")),
-                        base_color: Color::srgb(0.8, 0.8, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Out>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/front.png")),
-                        ..default()
-                    })));
-            },
-        );
-
-    // Back (-Z)
-    commands
-        .spawn(generate_viewcube_face!(
-            meshes,
-            materials,
-            plane,
-            StandardMaterial {
-                base_color_texture: Some(asset_server.load("cube/back.png")),
-                ..default()
-            },
-            Transform::from_matrix(Mat4::from_rotation_translation(
-                Quat::from_rotation_x(-crate::PI / 2.0) * Quat::from_rotation_y(crate::PI),
-                Vec3::new(0.0, 0.0, -half),
-            )),
-            ViewcubeHit(CubePart::Back)
-        ))
-        .observe(
-            move |trigger: Trigger<Pointer<Click>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(ViewcubeHit(CubePart::Back))
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/back.png")),
-                        base_color: Color::srgb(0.6, 0.6, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Over>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/back.png")),
-                        base_color: Color::srgb(0.8, 0.8, 1.0),
-                        ..default()
-                    })));
-            },
-        )
-        .observe(
-            move |trigger: Trigger<Pointer<Out>>,
-                  mut commands: Commands,
-                  mut materials: ResMut<Assets<StandardMaterial>>,
-                  asset_server: Res<AssetServer>| {
-                commands
-                    .entity(trigger.target)
-                    .insert(MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color_texture: Some(asset_server.load("cube/back.png")),
-                        ..default()
-                    })));
-            },
-        );
+    for (part, rotation, translation) in faces {
+        commands
+            .spawn(generate_viewcube_face!(
+                meshes,
+                materials,
+                plane,
+                StandardMaterial {
+                    base_color_texture: textures
+                        .get(part)
+                        .map(|texture| texture.load(asset_server)),
+                    ..default()
+                },
+                Transform::from_matrix(Mat4::from_rotation_translation(rotation, translation)),
+                ViewcubePart(part)
+            ))
+            .observe(on_part_click)
+            .observe(on_part_over)
+            .observe(on_part_out);
+    }
 }
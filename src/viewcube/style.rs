@@ -0,0 +1,100 @@
+use bevy::{color::Color, ecs::system::Resource};
+
+use super::CubePart;
+
+/// Colours, opacity and thresholds used to draw the viewcube.
+///
+/// Inserted with its default value by [`BevyViewCubePlugin`](super::BevyViewCubePlugin) if not
+/// already present, and can be changed at runtime.
+#[derive(Resource, Clone, Debug)]
+pub struct ViewcubeStyle {
+    /// Colour of the faces, multiplied with their texture.
+    pub face_color: Color,
+    /// Colour of the edges of the powerful viewcube, multiplied with their texture if any.
+    pub edge_color: Color,
+    /// Colour of the corners of the powerful viewcube, multiplied with their texture if any.
+    pub corner_color: Color,
+    /// Colour of the markers of the named views.
+    pub view_color: Color,
+    /// Colour of the buttons next to the cube.
+    pub button_color: Color,
+    /// Tint of a part under the pointer.
+    pub hover_color: Color,
+    /// Tint of a part that has just been clicked, until the pointer leaves it.
+    pub pressed_color: Color,
+    /// Tint of the part the bound camera is currently looking from, and of the level button while
+    /// the camera is upside down.
+    pub active_color: Color,
+    /// Colour of the parts turned off, or that the bound camera cannot reach within its yaw and
+    /// pitch limits.
+    pub disabled_color: Color,
+    /// Angle in radians between the camera direction and a part direction under which the part
+    /// counts as active.
+    pub active_tolerance: f32,
+    /// Opacity of the whole viewcube while the pointer is outside its viewport.
+    pub idle_opacity: f32,
+    /// Seconds without camera movement after which the viewcube fades out completely,
+    /// `None` to keep it shown.
+    pub hide_after: Option<f32>,
+    /// Opacity change per second while fading in or out.
+    pub fade_speed: f32,
+    /// Show back and forward buttons stepping through the [`ViewcubeHistory`] of the bound camera.
+    ///
+    /// [`ViewcubeHistory`]: super::ViewcubeHistory
+    pub history_buttons: bool,
+    /// Outline drawn along the cube edges, `None` to draw none. On the powerful viewcube it runs
+    /// along both borders of each bevelled edge, around the faces.
+    pub outline: Option<ViewcubeOutline>,
+}
+
+/// Outline along the 12 edges of the viewcube, see [`ViewcubeStyle::outline`].
+#[derive(Clone, Copy, Debug)]
+pub struct ViewcubeOutline {
+    pub color: Color,
+    /// Thickness of the outline, in the same units as the cube edge length of `0.8`.
+    pub width: f32,
+}
+
+impl Default for ViewcubeOutline {
+    fn default() -> Self {
+        Self {
+            color: Color::srgb(0.1, 0.1, 0.1),
+            width: 0.02,
+        }
+    }
+}
+
+impl Default for ViewcubeStyle {
+    fn default() -> Self {
+        Self {
+            face_color: Color::WHITE,
+            edge_color: Color::WHITE,
+            corner_color: Color::WHITE,
+            view_color: Color::srgb(0.2, 0.6, 1.0),
+            button_color: Color::srgb(0.85, 0.85, 0.85),
+            hover_color: Color::srgb(0.8, 0.8, 1.0),
+            pressed_color: Color::srgb(0.6, 0.6, 1.0),
+            active_color: Color::srgb(1.0, 0.85, 0.6),
+            disabled_color: Color::srgb(0.45, 0.45, 0.45),
+            active_tolerance: 0.5f32.to_radians(),
+            idle_opacity: 1.0,
+            hide_after: None,
+            fade_speed: 4.0,
+            history_buttons: false,
+            outline: None,
+        }
+    }
+}
+
+impl ViewcubeStyle {
+    /// Colour of `part` when not interacted with.
+    pub fn part_color(&self, part: CubePart) -> Color {
+        if part.is_edge() {
+            self.edge_color
+        } else if part.is_corner() {
+            self.corner_color
+        } else {
+            self.face_color
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use bevy::{
+    app::App,
+    asset::{embedded_asset, io::embedded::EmbeddedAssetRegistry, AssetServer, Assets, Handle},
+    ecs::{
+        change_detection::DetectChanges,
+        system::{Query, Res, ResMut, Resource},
+    },
+    image::Image,
+    pbr::{MeshMaterial3d, StandardMaterial},
+};
+
+use super::{CubePart, ViewcubePart};
+
+/// Default face textures, compiled into the binary by [`embed_default_textures`] so the
+/// viewcube is textured without copying them into the application's own assets directory.
+const DEFAULT_TEXTURES: [(CubePart, &str); 6] = [
+    (CubePart::Front, "bevy_viewcube/cube/front.png"),
+    (CubePart::Back, "bevy_viewcube/cube/back.png"),
+    (CubePart::Left, "bevy_viewcube/cube/left.png"),
+    (CubePart::Right, "bevy_viewcube/cube/right.png"),
+    (CubePart::Top, "bevy_viewcube/cube/top.png"),
+    (CubePart::Bottom, "bevy_viewcube/cube/bottom.png"),
+];
+
+/// Registers the default face textures with the `embedded://` asset source, under the paths of
+/// [`DEFAULT_TEXTURES`].
+pub(crate) fn embed_default_textures(app: &mut App) {
+    assert!(
+        app.world().contains_resource::<EmbeddedAssetRegistry>(),
+        "BevyViewCubePlugin needs the AssetPlugin, add DefaultPlugins or AssetPlugin before it"
+    );
+    // Paths are relative to this module's directory, `src/viewcube`
+    embedded_asset!(app, "viewcube", "cube/front.png");
+    embedded_asset!(app, "viewcube", "cube/back.png");
+    embedded_asset!(app, "viewcube", "cube/left.png");
+    embedded_asset!(app, "viewcube", "cube/right.png");
+    embedded_asset!(app, "viewcube", "cube/top.png");
+    embedded_asset!(app, "viewcube", "cube/bottom.png");
+}
+
+/// Asset path of the embedded default texture of a face, `None` for edges and corners.
+fn default_texture_path(part: CubePart) -> Option<String> {
+    DEFAULT_TEXTURES
+        .iter()
+        .find(|(face, _)| *face == part)
+        .map(|(_, path)| format!("embedded://{path}"))
+}
+
+/// Image for a viewcube part, either loaded from an asset path or an already loaded handle.
+#[derive(Clone, Debug)]
+pub enum ViewcubeTexture {
+    Path(String),
+    Handle(Handle<Image>),
+}
+
+impl ViewcubeTexture {
+    pub(crate) fn load(&self, asset_server: &AssetServer) -> Handle<Image> {
+        match self {
+            ViewcubeTexture::Path(path) => asset_server.load(path.clone()),
+            ViewcubeTexture::Handle(handle) => handle.clone(),
+        }
+    }
+}
+
+impl From<&str> for ViewcubeTexture {
+    fn from(path: &str) -> Self {
+        ViewcubeTexture::Path(path.to_string())
+    }
+}
+
+impl From<String> for ViewcubeTexture {
+    fn from(path: String) -> Self {
+        ViewcubeTexture::Path(path)
+    }
+}
+
+impl From<Handle<Image>> for ViewcubeTexture {
+    fn from(handle: Handle<Image>) -> Self {
+        ViewcubeTexture::Handle(handle)
+    }
+}
+
+/// Textures of the viewcube parts.
+///
+/// Defaults to the embedded face textures and untextured edges and corners. Inserted by
+/// [`BevyViewCubePlugin`](super::BevyViewCubePlugin) if not already present, and can be changed
+/// at runtime.
+#[derive(Resource, Clone, Debug)]
+pub struct ViewcubeTextures {
+    pub front: Option<ViewcubeTexture>,
+    pub back: Option<ViewcubeTexture>,
+    pub left: Option<ViewcubeTexture>,
+    pub right: Option<ViewcubeTexture>,
+    pub top: Option<ViewcubeTexture>,
+    pub bottom: Option<ViewcubeTexture>,
+    /// Texture shared by the 12 edges of the powerful viewcube.
+    pub edges: Option<ViewcubeTexture>,
+    /// Texture shared by the 8 corners of the powerful viewcube.
+    pub corners: Option<ViewcubeTexture>,
+}
+
+impl Default for ViewcubeTextures {
+    fn default() -> Self {
+        let face = |part| default_texture_path(part).map(ViewcubeTexture::Path);
+        Self {
+            front: face(CubePart::Front),
+            back: face(CubePart::Back),
+            left: face(CubePart::Left),
+            right: face(CubePart::Right),
+            top: face(CubePart::Top),
+            bottom: face(CubePart::Bottom),
+            edges: None,
+            corners: None,
+        }
+    }
+}
+
+impl ViewcubeTextures {
+    /// Texture used for `part`.
+    pub fn get(&self, part: CubePart) -> Option<&ViewcubeTexture> {
+        match part {
+            CubePart::Front => self.front.as_ref(),
+            CubePart::Back => self.back.as_ref(),
+            CubePart::Left => self.left.as_ref(),
+            CubePart::Right => self.right.as_ref(),
+            CubePart::Top => self.top.as_ref(),
+            CubePart::Bottom => self.bottom.as_ref(),
+            part if part.is_edge() => self.edges.as_ref(),
+            _ => self.corners.as_ref(),
+        }
+    }
+
+    /// Sets the texture of a face, or of all edges or all corners when `part` is one of them.
+    pub fn set(&mut self, part: CubePart, texture: impl Into<ViewcubeTexture>) {
+        let texture = Some(texture.into());
+        match part {
+            CubePart::Front => self.front = texture,
+            CubePart::Back => self.back = texture,
+            CubePart::Left => self.left = texture,
+            CubePart::Right => self.right = texture,
+            CubePart::Top => self.top = texture,
+            CubePart::Bottom => self.bottom = texture,
+            part if part.is_edge() => self.edges = texture,
+            _ => self.corners = texture,
+        }
+    }
+}
+
+/// Applies [`ViewcubeTextures`] to the part materials whenever it changes.
+pub(crate) fn update_textures(
+    textures: Res<ViewcubeTextures>,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    parts: Query<(&ViewcubePart, &MeshMaterial3d<StandardMaterial>)>,
+) {
+    if !textures.is_changed() {
+        return;
+    }
+    for (part, material) in parts.iter() {
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.base_color_texture = textures
+                .get(part.0)
+                .map(|texture| texture.load(&asset_server));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use bevy::{prelude::*, render::view::RenderLayers};
+
+use super::{
+    on_part_out, on_part_over, ViewcubeCenter, ViewcubeMesh, ViewcubePartState, ViewcubeSnap,
+    CUBE_SIZE,
+};
+
+/// Radius of the marker drawn on the cube for each named view.
+const MARKER_RADIUS: f32 = 0.05;
+
+/// A named camera view, in addition to the 26 [`CubePart`](super::CubePart) directions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewcubeView {
+    pub name: String,
+    /// From the focus towards the camera, in the frame of the cube: +Y is `Top` and +Z `Front`.
+    ///
+    /// Snapping to a view whose direction is zero or not finite does nothing.
+    pub direction: Vec3,
+    /// Rotation in radians around the view direction.
+    ///
+    /// Not applied when snapping: `PanOrbitCamera` always keeps the camera upright and has no
+    /// roll. It is kept on the view for applications driving the camera themselves.
+    pub roll: f32,
+    /// Distance to the focus, the current one is kept if `None`.
+    pub radius: Option<f32>,
+    /// Point to look at, the current one is kept if `None`.
+    pub focus: Option<Vec3>,
+}
+
+impl ViewcubeView {
+    pub fn new(name: impl Into<String>, direction: Vec3) -> Self {
+        Self {
+            name: name.into(),
+            direction,
+            roll: 0.0,
+            radius: None,
+            focus: None,
+        }
+    }
+
+    pub fn with_roll(mut self, roll: f32) -> Self {
+        self.roll = roll;
+        self
+    }
+
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    pub fn with_focus(mut self, focus: Vec3) -> Self {
+        self.focus = Some(focus);
+        self
+    }
+}
+
+/// Named views shown as clickable markers on the viewcube.
+///
+/// Inserted empty by [`BevyViewCubePlugin`](super::BevyViewCubePlugin) if not already present,
+/// the markers follow any change at runtime. Send [`ViewcubeSnap::View`] to snap to a view from
+/// code.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct ViewcubeViews {
+    pub views: Vec<ViewcubeView>,
+}
+
+impl ViewcubeViews {
+    /// Adds a view, replacing the one with the same name if any.
+    pub fn add(&mut self, view: ViewcubeView) {
+        match self.views.iter_mut().find(|v| v.name == view.name) {
+            Some(existing) => *existing = view,
+            None => self.views.push(view),
+        }
+    }
+
+    /// Removes the view named `name`, returning it if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<ViewcubeView> {
+        let index = self.views.iter().position(|v| v.name == name)?;
+        Some(self.views.remove(index))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ViewcubeView> {
+        self.views.iter().find(|v| v.name == name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ViewcubeView> {
+        self.views.iter()
+    }
+}
+
+/// Marker of the named view at this index of [`ViewcubeViews::views`].
+#[derive(Component)]
+pub(crate) struct ViewcubeViewMarker(pub usize);
+
+/// Where the marker of a view sits: on the cube surface along its direction.
+fn marker_position(direction: Vec3) -> Vec3 {
+    let direction = direction.normalize_or(Vec3::Z);
+    direction * (CUBE_SIZE / 2.0 / direction.abs().max_element())
+}
+
+fn on_view_click(
+    trigger: Trigger<Pointer<Click>>,
+    views: Res<ViewcubeViews>,
+    mut markers: Query<(&ViewcubeViewMarker, &mut ViewcubePartState)>,
+    mut snaps: EventWriter<ViewcubeSnap>,
+) {
+    let Ok((marker, mut state)) = markers.get_mut(trigger.entity()) else {
+        return;
+    };
+    if let Some(view) = views.views.get(marker.0) {
+        state.pressed = true;
+        snaps.send(ViewcubeSnap::To(view.clone()));
+    }
+}
+
+/// Rebuilds the view markers whenever [`ViewcubeViews`] changes.
+pub(crate) fn update_markers(
+    mut commands: Commands,
+    views: Res<ViewcubeViews>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    center: Query<Entity, With<ViewcubeCenter>>,
+    markers: Query<Entity, With<ViewcubeViewMarker>>,
+) {
+    if !views.is_changed() {
+        return;
+    }
+    for marker in markers.iter() {
+        commands.entity(marker).despawn_recursive();
+    }
+    let Ok(center) = center.get_single() else {
+        return;
+    };
+    let mesh = meshes.add(Sphere::new(MARKER_RADIUS));
+    commands.entity(center).with_children(|builder| {
+        for (index, view) in views.iter().enumerate() {
+            builder
+                .spawn((
+                    Mesh3d(mesh.clone()),
+                    MeshMaterial3d(materials.add(StandardMaterial::default())),
+                    Transform::from_translation(marker_position(view.direction)),
+                    RenderLayers::layer(13),
+                    ViewcubeViewMarker(index),
+                    ViewcubePartState::default(),
+                    ViewcubeMesh,
+                ))
+                .observe(on_view_click)
+                .observe(on_part_over)
+                .observe(on_part_out);
+        }
+    });
+}